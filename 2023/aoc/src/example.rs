@@ -0,0 +1,127 @@
+use crate::{Answer, Part};
+
+///
+/// A sample input of the puzzle text, with the answers given for it.
+///
+/// # Expected format:
+///
+/// ```text
+/// part1: 114
+/// part2: 2
+///
+/// 0 3 6 9 12 15
+/// 1 3 6 10 15 21
+/// ```
+///
+/// The answers come first, one per line, and the input starts after the first blank line.
+/// A part without an answer is not checked, since some samples only apply to one part.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub expected: Vec<(Part, String)>,
+    pub input: String,
+}
+
+impl Example {
+    /// Answer given for the part, as a number when it is one.
+    pub fn answer(&self, part: Part) -> Option<Answer> {
+        self.expected
+            .iter()
+            .find(|(expected_part, _)| *expected_part == part)
+            .map(|(_, answer)| match answer.parse::<i128>() {
+                Ok(number) => Answer::Number(number),
+                Err(_) => Answer::Text(answer.clone()),
+            })
+    }
+}
+
+pub fn parse_example(name: &str, content: &str) -> Result<Example, String> {
+    let (header, input) = content
+        .split_once("\n\n")
+        .ok_or_else(|| format!("{}: missing blank line before the input", name))?;
+
+    let mut expected = vec![];
+    for line in header.lines() {
+        let (part, answer) = line
+            .split_once(':')
+            .ok_or_else(|| format!("{}: expected 'part<n>: <answer>', found '{}'", name, line))?;
+        let part = match part.trim() {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            other => return Err(format!("{}: unknown part '{}'", name, other)),
+        };
+        expected.push((part, answer.trim().to_string()));
+    }
+
+    Ok(Example {
+        name: name.to_string(),
+        expected,
+        input: input.trim_end_matches('\n').to_string(),
+    })
+}
+
+///
+/// Example of a day embedded at compile time, e.g. `example!(9, "sample")` for
+/// `examples/day9/sample.txt`, so that the days and the runner share the same files.
+///
+/// Panics when the file does not follow the format of `Example`.
+///
+#[macro_export]
+macro_rules! example {
+    ($day:literal, $name:literal) => {
+        $crate::example::parse_example(
+            $name,
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../examples/day",
+                $day,
+                "/",
+                $name,
+                ".txt"
+            )),
+        )
+        .unwrap()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_example_file_when_parse_then_answers_and_input() {
+        let example = parse_example("sample", "part1: 114\npart2: -3\n\n0 3 6\n\n1 2 3\n").unwrap();
+
+        assert_eq!(
+            example,
+            Example {
+                name: "sample".to_string(),
+                expected: vec![
+                    (Part::One, "114".to_string()),
+                    (Part::Two, "-3".to_string())
+                ],
+                input: "0 3 6\n\n1 2 3".to_string(),
+            }
+        );
+        assert_eq!(example.answer(Part::Two), Some(Answer::Number(-3)));
+    }
+
+    #[test]
+    fn given_invalid_example_files_when_parse_then_error() {
+        assert!(parse_example("a", "part1: 1\n1 2 3").is_err());
+        assert!(parse_example("b", "part3: 1\n\n1 2 3").is_err());
+        assert!(parse_example("c", "114\n\n1 2 3").is_err());
+    }
+
+    #[test]
+    fn given_text_or_missing_answer_when_answer_then_text_or_none() {
+        let example = parse_example("sample", "part1: ZZZ\n\nAAA").unwrap();
+
+        assert_eq!(
+            example.answer(Part::One),
+            Some(Answer::Text("ZZZ".to_string()))
+        );
+        assert_eq!(example.answer(Part::Two), None);
+    }
+}
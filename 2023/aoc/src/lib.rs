@@ -0,0 +1,170 @@
+//!
+//! Common interface of the Advent of Code 2023 solutions, so that every day can be parsed, solved
+//! and checked the same way.
+//!
+
+use std::error::Error;
+use std::fmt;
+
+pub mod example;
+pub mod log;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
+/// Parsing or solving a puzzle fails with any error that can be displayed.
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The part is not solved yet.
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+///
+/// A puzzle of one day: the input is parsed once, then both parts are solved from the parsed input.
+///
+/// ```text
+/// let parsed = Day9::parse(input)?;
+/// let answers = (Day9::part1(&parsed)?, Day9::part2(&parsed)?);
+/// ```
+///
+pub trait Solution {
+    /// Day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer>;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Parse the input and solve only the given part.
+pub fn solve_part<S: Solution>(input: &str, part: Part) -> Result<Answer> {
+    let parsed = S::parse(input)?;
+    match part {
+        Part::One => S::part1(&parsed),
+        Part::Two => S::part2(&parsed),
+    }
+}
+
+/// Parse the input and solve both parts.
+pub fn solve<S: Solution>(input: &str) -> Result<(Answer, Answer)> {
+    let parsed = S::parse(input)?;
+    Ok((S::part1(&parsed)?, S::part2(&parsed)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct WordCount;
+
+    impl Solution for WordCount {
+        const DAY: u8 = 0;
+
+        type Parsed = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+            if input.is_empty() {
+                return Err("empty input".into());
+            }
+            Ok(input.split_whitespace().map(str::to_string).collect())
+        }
+
+        fn part1(words: &Self::Parsed) -> Result<Answer> {
+            Ok(words.len().into())
+        }
+
+        fn part2(words: &Self::Parsed) -> Result<Answer> {
+            Ok(words.last().unwrap().as_str().into())
+        }
+    }
+
+    #[test]
+    fn given_solution_when_solve_then_both_answers() {
+        assert_eq!(
+            solve::<WordCount>("a b c").unwrap(),
+            (Answer::Number(3), Answer::Text("c".to_string()))
+        );
+    }
+
+    #[test]
+    fn given_solution_when_solve_part_then_only_that_part() {
+        assert_eq!(
+            solve_part::<WordCount>("a b c", Part::Two).unwrap(),
+            Answer::Text("c".to_string())
+        );
+    }
+
+    #[test]
+    fn given_invalid_input_when_solve_then_parse_error() {
+        let error = solve::<WordCount>("").unwrap_err();
+
+        assert_eq!(error.to_string(), "empty input");
+    }
+
+    #[test]
+    fn given_answers_when_display_then_plain_values() {
+        assert_eq!(Answer::from(114u64).to_string(), "114");
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+}
@@ -0,0 +1,166 @@
+//!
+//! Diagnostics of the solutions, written to stderr when their level is enabled, so that normal runs
+//! print only the answers.
+//!
+//! The level comes from `--log <level>` (or `--verbose` for debug) on the command line, else from
+//! the `AOC_LOG` environment variable, and is `warn` by default:
+//!
+//! ```text
+//! AOC_LOG=debug cargo run -p day7
+//! cargo run -p runner -- verify --day 8 --log trace
+//! ```
+//!
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Verbosity of a diagnostic, from the most to the least important.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Level::Error => write!(f, "ERROR"),
+            Level::Warn => write!(f, "WARN"),
+            Level::Info => write!(f, "INFO"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "unknown log level '{}', expected error, warn, info, debug or trace",
+                s
+            )),
+        }
+    }
+}
+
+/// Most verbose level written, 0 when nothing is.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+pub fn set_max_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Level asked for by the arguments, e.g. `--log debug` or `--verbose`, else by `AOC_LOG`.
+pub fn level_from(args: &[String], env: Option<&str>) -> Result<Option<Level>, String> {
+    let option = args
+        .iter()
+        .position(|arg| arg == "--log")
+        .and_then(|index| args.get(index + 1));
+    match (option, env) {
+        (Some(level), _) => level.parse().map(Some),
+        (None, _) if args.iter().any(|arg| arg == "--verbose") => Ok(Some(Level::Debug)),
+        (None, Some(level)) => level.parse().map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Set the level from the command line or the environment, warning about an unknown level.
+pub fn init() {
+    let args: Vec<String> = std::env::args().collect();
+    match level_from(&args, std::env::var("AOC_LOG").ok().as_deref()) {
+        Ok(Some(level)) => set_max_level(Some(level)),
+        Ok(None) => {}
+        Err(error) => eprintln!("warning: {}", error),
+    }
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    eprintln!("[{} {}] {}", level, target, args);
+}
+
+/// Write a diagnostic at the given level, formatted like `println!`, if the level is enabled.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn given_levels_when_compare_then_trace_is_most_verbose() {
+        assert!(Level::Error < Level::Warn);
+        assert!(Level::Debug < Level::Trace);
+        assert_eq!("DEBUG".parse::<Level>(), Ok(Level::Debug));
+        assert!("loud".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn given_flag_or_env_when_level_from_then_flag_wins() {
+        assert_eq!(level_from(&args("day8"), None), Ok(None));
+        assert_eq!(
+            level_from(&args("day8"), Some("info")),
+            Ok(Some(Level::Info))
+        );
+        assert_eq!(
+            level_from(&args("day8 --verbose"), Some("info")),
+            Ok(Some(Level::Debug))
+        );
+        assert_eq!(
+            level_from(&args("day8 --log trace --verbose"), Some("info")),
+            Ok(Some(Level::Trace))
+        );
+        assert!(level_from(&args("day8 --log loud"), None).is_err());
+    }
+}
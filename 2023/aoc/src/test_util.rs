@@ -0,0 +1,55 @@
+//!
+//! Helpers shared by the tests of every day, enabled with the `test-util` feature.
+//!
+//! The property tests and the benchmarks draw their inputs from a seeded generator, so that a failure
+//! can be replayed. Benchmarks are ignored tests named `bench_...`, run them with
+//! `cargo test --release -- --ignored --nocapture bench_`.
+//!
+
+/// Minimal linear congruential generator, enough to sample inputs without external crates.
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    /// Next value in `0..bound`. Panics when `bound` is zero, as the range is then empty.
+    pub fn next(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Lcg::next needs a bound above zero");
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % bound
+    }
+
+    /// Next value in `-bound..=bound`. Panics when `bound` is negative.
+    pub fn next_signed(&mut self, bound: i64) -> i64 {
+        assert!(bound >= 0, "Lcg::next_signed needs a bound of zero or more");
+        self.next(2 * bound as u64 + 1) as i64 - bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_same_seed_when_next_then_same_values_within_bounds() {
+        let values = |seed| {
+            let mut rng = Lcg::new(seed);
+            (0..100).map(|_| rng.next_signed(3)).collect::<Vec<i64>>()
+        };
+
+        assert_eq!(values(7), values(7));
+        assert_ne!(values(7), values(8));
+        assert!(values(7).iter().all(|value| (-3..=3).contains(value)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Lcg::next needs a bound above zero")]
+    fn given_zero_bound_when_next_then_panic_with_message() {
+        Lcg::new(7).next(0);
+    }
+}
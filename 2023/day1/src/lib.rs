@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use aoc::example::Example;
+use aoc::{Answer, Result, Solution};
+
+/*
+
+Exercise https://adventofcode.com/2023/day/1
+
+**/
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<String>;
+
+    ///
+    /// One calibration value per line, each line with at least a digit.
+    ///
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| if line.contains(|c: char| c.is_ascii_digit()) {
+                Ok(line.to_string())
+            } else {
+                Err(format!("no digit in calibration line '{}'", line).into())
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Parsed) -> Result<Answer> {
+        Ok(compute_sum(lines.iter().map(String::as_str).collect()).into())
+    }
+
+    fn part2(_lines: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+pub fn sample() -> Example {
+    aoc::example!(1, "sample")
+}
+
+fn compute_sum(values: Vec<&str>) -> u32 {
+    values.iter()
+        .map(|str| filter_2digit(str))
+        .map(|str| u32::from_str(str.as_str()).unwrap())
+        .sum()
+}
+
+fn filter_2digit(str: &str) -> String {
+    let decomposed_numbers: Vec<&str> = str
+        .matches(|c: char| c.is_ascii_digit())
+        .collect();
+    decomposed_numbers.first().unwrap().to_string() + decomposed_numbers.last().unwrap()
+}
+
+#[test]
+fn test_compute_sum() {
+    let list = vec!["1abc2", "pqr3stu8vwx"];
+    let result = compute_sum(list);
+    assert_eq!(result, 50);
+}
+
+#[test]
+fn given_sample_when_part1_then_142() {
+    let lines = Day1::parse(&sample().input).unwrap();
+
+    assert_eq!(Day1::part1(&lines).unwrap(), Answer::Number(142));
+}
+
+#[test]
+fn given_line_without_digit_when_parse_then_error() {
+    assert!(Day1::parse("1abc2\nabc").is_err());
+}
+
+#[test]
+fn given_line_with_only_non_ascii_numerals_when_parse_then_error() {
+    assert!(Day1::parse("1abc2\na½b").is_err());
+}
+
+#[test]
+fn given_non_ascii_numeral_between_digits_when_part1_then_ignored() {
+    let lines = Day1::parse("½1a2½").unwrap();
+
+    assert_eq!(Day1::part1(&lines).unwrap(), Answer::Number(12));
+}
+
+#[test]
+fn given_string_with_2digits_when_filter_then_return_2digits() {
+    let str = "pqr3stu8vwx";
+    let result = filter_2digit(str);
+    assert_eq!(result, "38");
+}
+
+#[test]
+fn given_string_with_3digits_when_filter_then_return_2digits() {
+    let str = "pqr3s6tu8vwx";
+    let result = filter_2digit(str);
+    assert_eq!(result, "38");
+}
+
+#[test]
+fn given_string_with_1digit_when_filter_then_return_2digits() {
+    let str = "pqrs8vwx";
+    let result = filter_2digit(str);
+    assert_eq!(result, "88");
+}
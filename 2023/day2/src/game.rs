@@ -19,8 +19,12 @@ impl Game {
         self.extractions.as_ref()
     }
 
+    /// Every color must fit in the request: the extractions are not compared as a whole.
     pub fn is_compatible_with_request(&self, request: &Extraction) -> bool {
-        &self.max_extraction() <= request
+        let max_extraction = self.max_extraction();
+        max_extraction.red <= request.red
+            && max_extraction.blue <= request.blue
+            && max_extraction.green <= request.green
     }
 
     fn max_extraction(&self) -> Extraction {
@@ -39,7 +43,7 @@ impl std::fmt::Display for Game {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct Extraction {
     red: u8,
     blue: u8,
@@ -54,6 +58,12 @@ impl Ord for Extraction {
     }
 }
 
+impl PartialOrd for Extraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Extraction {
     pub fn new(red: u8, blue: u8, green: u8) -> Self {
         Self { red, blue, green }
@@ -84,6 +94,7 @@ fn given_a_game_when_compute_max_extractions_then_match() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn given_a_game_and_a_request_when_check_compatibility_then_false() {
     let game = Game::new(1, vec![Extraction::new(12, 10, 5)]);
     let request = Extraction::new(10, 10, 5);
@@ -93,3 +104,10 @@ fn given_a_game_and_a_request_when_check_compatibility_then_false() {
     assert_eq!(is_compatible, false);
 }
 
+#[test]
+fn given_a_game_with_more_blue_than_requested_when_check_compatibility_then_false() {
+    let game = Game::new(1, vec![Extraction::new(5, 20, 1)]);
+    let request = Extraction::new(12, 14, 13);
+
+    assert!(!game.is_compatible_with_request(&request));
+}
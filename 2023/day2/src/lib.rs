@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use aoc::example::Example;
+use aoc::{Answer, Result, Solution};
+
+pub use crate::game::{Extraction, Game};
+
+mod game;
+
+/*
+Exercise https://adventofcode.com/2023/day/2
+ **/
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        if let Some(line) = input.lines()
+            .filter(|line| !line.trim().is_empty())
+            .find(|line| !line.starts_with("Game ") || !line.contains(':')) {
+            return Err(format!("expected 'Game <id>: <extractions>', found '{}'", line).into());
+        }
+        Ok(parse_list_games(input)?)
+    }
+
+    /// Sum of the ids of the games possible with the bag of the puzzle.
+    fn part1(games: &Self::Parsed) -> Result<Answer> {
+        let request = puzzle_request();
+        let sum: u32 = games.iter()
+            .filter(|game| game.is_compatible_with_request(&request))
+            .map(|game| game.id() as u32)
+            .sum();
+        Ok(sum.into())
+    }
+
+    fn part2(_games: &Self::Parsed) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+pub fn sample() -> Example {
+    aoc::example!(2, "sample")
+}
+
+/// The bag holds 12 red cubes, 14 blue cubes and 13 green cubes.
+pub fn puzzle_request() -> Extraction {
+    Extraction::new(12, 14, 13)
+}
+
+fn parse_list_games(games: &str) -> std::result::Result<Vec<Game>, String> {
+    games.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_single_game_row)
+        .collect()
+}
+
+///
+/// # Expected format:
+///
+/// ```text
+/// Game 1: 1 blue, 2 green, 3 red
+/// ```
+fn parse_single_game_row(game_string: &str) -> std::result::Result<Game, String> {
+    // extract the first part of string 'Game x' by ':'
+    let game_splitted: Vec<&str> = game_string.split(':').collect();
+    let game_id: u8 = extract_number_from_string(game_splitted.first().unwrap())?;
+
+    // extract the rest of string aka extractions, by ';'
+    let mut extractions: Vec<Extraction> = vec![];
+    for extraction in game_splitted.last().unwrap().split(';') {
+        extractions.push(parse_extraction(extraction)?);
+    }
+
+    Ok(Game::new(game_id, extractions))
+}
+
+///
+/// Parse a string and return the representation of it as Extraction.
+///
+/// # Expected format:
+/// ```text
+/// 1 blue, 2 green, 3 red
+/// ```
+///
+fn parse_extraction(extraction_string: &str) -> std::result::Result<Extraction, String> {
+    let string_splitted: Vec<&str> = extraction_string.split(',').collect();
+    let mut blue_cubes = 0;
+    let mut red_cubes = 0;
+    let mut green_cubes = 0;
+    for cube in string_splitted.iter().map(|str| str.trim()) {
+        if cube.ends_with("blue") {
+            blue_cubes = extract_number_from_string(cube)?;
+        } else if cube.ends_with("red") {
+            red_cubes = extract_number_from_string(cube)?;
+        } else if cube.ends_with("green") {
+            green_cubes = extract_number_from_string(cube)?;
+        } else {
+            return Err(format!("expected '<number> <blue|red|green>', found '{}'", cube));
+        }
+    }
+    Ok(Extraction::new(red_cubes, blue_cubes, green_cubes))
+}
+
+///
+/// # Expected format:
+/// ```text
+/// <number-multi-digits> <other string>
+/// ```
+///
+fn extract_number_from_string(string: &str) -> std::result::Result<u8, String> {
+    let str: String = string.matches(char::is_numeric)
+        .flat_map(|s| s.chars())
+        .collect();
+    u8::from_str(str.as_str())
+        .map_err(|_| format!("expected a number up to 255 in '{}'", string))
+}
+
+#[test]
+fn given_a_bunch_of_games_as_string_when_parse_then_ok() {
+    let str = "Game 1: 1 blue, 2 green, 3 red; 1 red, 2 green\n\
+    Game 2: 2 blue, 3 green; 3 red";
+
+    let parsed_games: Vec<Game> = parse_list_games(str).unwrap();
+
+    assert_eq!(parsed_games.len(), 2);
+    assert_eq!(parsed_games.last().unwrap().id(), 2);
+}
+
+#[test]
+fn given_sample_when_part1_then_8() {
+    let games = Day2::parse(&sample().input).unwrap();
+
+    assert_eq!(Day2::part1(&games).unwrap(), Answer::Number(8));
+}
+
+#[test]
+fn given_invalid_game_when_parse_then_error() {
+    assert!(Day2::parse("Game 1: 1 blue\n2 red").is_err());
+}
+
+#[test]
+fn given_blank_lines_between_games_when_parse_then_skipped() {
+    let games = Day2::parse("Game 1: 1 blue\n\nGame 2: 2 red\n").unwrap();
+
+    assert_eq!(games.len(), 2);
+}
+
+#[test]
+fn given_a_game_as_string_when_parse_then_ok() {
+    let str = "Game 1: 1 blue, 2 green, 3 red; 1 red, 2 green";
+
+    let game = parse_single_game_row(str).unwrap();
+
+    assert_eq!(game.id(), 1);
+    assert_eq!(game.extractions().len(), 2);
+    assert_eq!(game.extractions().first().unwrap(), &Extraction::new(3, 1, 2));
+    assert_eq!(game.extractions().last().unwrap(), &Extraction::new(1, 0, 2));
+}
+
+
+#[test]
+fn given_3_extractions_when_parse_then_ok() {
+    let str = "1 blue, 2 green, 3 red";
+
+    let extraction = parse_extraction(str).unwrap();
+
+    assert_eq!(extraction.red(), 3);
+    assert_eq!(extraction.blue(), 1);
+    assert_eq!(extraction.green(), 2);
+}
+
+#[test]
+fn given_extractions_2digit_when_parse_then_ok() {
+    let str = "10 blue, 2 green, 3 red";
+
+    let extraction = parse_extraction(str).unwrap();
+
+    assert_eq!(extraction.red(), 3);
+    assert_eq!(extraction.blue(), 10);
+    assert_eq!(extraction.green(), 2);
+}
+
+#[test]
+fn given_games_with_two_digit_ids_when_part1_then_ids_are_summed() {
+    let games = Day2::parse("Game 12: 1 blue\nGame 45: 20 red\nGame 100: 2 green\n").unwrap();
+
+    assert_eq!(games.iter().map(Game::id).collect::<Vec<u8>>(), vec![12, 45, 100]);
+    assert_eq!(Day2::part1(&games).unwrap(), Answer::Number(112));
+}
+
+#[test]
+fn given_malformed_extractions_when_parse_then_error() {
+    assert_eq!(
+        Day2::parse("Game 1: 3 blue, 4 purple").unwrap_err().to_string(),
+        "expected '<number> <blue|red|green>', found '4 purple'"
+    );
+    assert!(Day2::parse("Game 1: 300 blue").is_err());
+    assert!(Day2::parse("Game one: 3 blue").is_err());
+}
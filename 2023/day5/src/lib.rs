@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+
+use aoc::example::Example;
+use aoc::{Answer, Solution};
+
+pub use crate::trace::{format_trace_table, SeedTrace};
+use crate::trace::{parse_map_lines, section_names, ConversionStep, MapLine, CATEGORIES};
+pub use crate::validation::{validate, Strictness};
+
+mod trace;
+mod validation;
+
+/*
+
+Exercise https://adventofcode.com/2023/day/5
+
+--- Day 5: If You Give A Seed A Fertilizer ---
+You take the boat and find the gardener right where you were told he would be: managing a giant "garden" that looks more to you like a farm.
+
+"A water source? Island Island is the water source!" You point out that Snow Island isn't receiving any water.
+
+"Oh, we had to stop the water because we ran out of sand to filter it with! Can't make snow with dirty water. Don't worry, I'm sure we'll get more sand soon; we only turned off the water a few days... weeks... oh no." His face sinks into a look of horrified realization.
+
+"I've been so busy making sure everyone here has food that I completely forgot to check why we stopped getting more sand! There's a ferry leaving soon that is headed over in that direction - it's much faster than your boat. Could you please go check it out?"
+
+You barely have time to agree to this request when he brings up another. "While you wait for the ferry, maybe you can help us with our food production problem. The latest Island Island Almanac just arrived and we're having trouble making sense of it."
+
+The almanac (your puzzle input) lists all of the seeds that need to be planted. It also lists what type of soil to use with each kind of seed, what type of fertilizer to use with each kind of soil, what type of water to use with each kind of fertilizer, and so on. Every type of seed, soil, fertilizer and so on is identified with a number, but numbers are reused by each category - that is, soil 123 and fertilizer 123 aren't necessarily related to each other.
+
+For example:
+
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+The almanac starts by listing which seeds need to be planted: seeds 79, 14, 55, and 13.
+
+The rest of the almanac contains a list of maps which describe how to convert numbers from a source category into numbers in a destination category. That is, the section that starts with seed-to-soil map: describes how to convert a seed number (the source) to a soil number (the destination). This lets the gardener and his team know which soil to use with which seeds, which water to use with which fertilizer, and so on.
+
+Rather than list every source number and its corresponding destination number one by one, the maps describe entire ranges of numbers that can be converted. Each line within a map contains three numbers: the destination range start, the source range start, and the range length.
+
+Consider again the example seed-to-soil map:
+
+50 98 2
+52 50 48
+The first line has a destination range start of 50, a source range start of 98, and a range length of 2. This line means that the source range starts at 98 and contains two values: 98 and 99. The destination range is the same length, but it starts at 50, so its two values are 50 and 51. With this information, you know that seed number 98 corresponds to soil number 50 and that seed number 99 corresponds to soil number 51.
+
+The second line means that the source range starts at 50 and contains 48 values: 50, 51, ..., 96, 97. This corresponds to a destination range starting at 52 and also containing 48 values: 52, 53, ..., 98, 99. So, seed number 53 corresponds to soil number 55.
+
+Any source numbers that aren't mapped correspond to the same destination number. So, seed number 10 corresponds to soil number 10.
+
+So, the entire list of seed numbers and their corresponding soil numbers looks like this:
+
+seed  soil
+0     0
+1     1
+...   ...
+48    48
+49    49
+50    52
+51    53
+...   ...
+96    98
+97    99
+98    50
+99    51
+With this map, you can look up the soil number required for each initial seed number:
+
+Seed number 79 corresponds to soil number 81.
+Seed number 14 corresponds to soil number 14.
+Seed number 55 corresponds to soil number 57.
+Seed number 13 corresponds to soil number 13.
+The gardener and his team want to get started as soon as possible, so they'd like to know the closest location that needs a seed. Using these maps, find the lowest location number that corresponds to any of the initial seeds. To do this, you'll need to convert each seed number through other categories until you can find its corresponding location number. In this example, the corresponding types are:
+
+Seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82.
+Seed 14, soil 14, fertilizer 53, water 49, light 42, temperature 42, humidity 43, location 43.
+Seed 55, soil 57, fertilizer 57, water 53, light 46, temperature 82, humidity 82, location 86.
+Seed 13, soil 13, fertilizer 52, water 41, light 34, temperature 34, humidity 35, location 35.
+So, the lowest location number in this example is 35.
+
+What is the lowest location number that corresponds to any of the initial seed numbers?
+
+
+**/
+
+/*
+There are 2 ways I can think of to represent the almanac:
+- a hashmap for each category, where the key is the source category and the value is the destination category.
+- an array of linked lists that already "resolves" the chain of categories, e.g. [0] is the seed, [1] is the soil, [2] is the fertilizer, etc.
+    The data structure may as well be an array 2d
+-
+
+The first one is more flexible, but the second one is more efficient for lookups.
+**/
+
+pub struct Almanac {
+    pub seeds: Vec<u8>,
+    seed_to_soil: HashMap<u8, u8>,
+    soil_to_fertilizer: HashMap<u8, u8>,
+    fertilizer_to_water: HashMap<u8, u8>,
+    water_to_light: HashMap<u8, u8>,
+    light_to_temperature: HashMap<u8, u8>,
+    temperature_to_humidity: HashMap<u8, u8>,
+    humidity_to_location: HashMap<u8, u8>,
+    map_lines: Vec<Vec<MapLine>>,
+}
+
+impl Almanac {
+    ///
+    /// Parse the seeds, then every map in conversion order, failing on values that do not fit in `u8`
+    /// or maps that are missing or out of order.
+    ///
+    pub fn new(input: String) -> Result<Self, String> {
+        // every line keeps its 1-based number, so that the trace can point back to the map lines
+        let mut lines = input
+            .lines()
+            .map(str::trim)
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .filter(|(_, line)| !line.is_empty());
+        let seeds: Vec<u8> = lines
+            .next()
+            .and_then(|(_, line)| line.strip_prefix("seeds:"))
+            .ok_or("expected 'seeds:' on the first line")?
+            .split_whitespace()
+            .map(|num| {
+                num.parse::<u8>()
+                    .map_err(|_| format!("seed '{}' is not between 0 and 255", num))
+            })
+            .collect::<Result<_, _>>()?;
+        aoc::debug!("seeds: {:?}", seeds);
+
+        let mut sections: Vec<(&str, Vec<(usize, &str)>)> = vec![];
+        for (line_number, line) in lines {
+            match line.strip_suffix(" map:") {
+                Some(header) => sections.push((header, vec![])),
+                None => sections
+                    .last_mut()
+                    .ok_or_else(|| format!("range '{}' outside of a map", line))?
+                    .1
+                    .push((line_number, line)),
+            }
+        }
+        let headers: Vec<&str> = sections.iter().map(|(header, _)| *header).collect();
+        let expected_headers = section_names();
+        if headers != expected_headers {
+            return Err(format!(
+                "expected the maps {}, found {}",
+                expected_headers.join(", "),
+                headers.join(", ")
+            ));
+        }
+
+        let map_lines = sections
+            .iter()
+            .map(|(header, ranges)| parse_map_lines(header, ranges))
+            .collect::<Result<Vec<Vec<MapLine>>, String>>()?;
+        let mut maps = map_lines.iter().map(|lines| build_map(lines));
+        // the fields are initialized in conversion order, like the maps
+        let mut next_map = || maps.next().ok_or("missing map");
+
+        Ok(Self {
+            seeds,
+            seed_to_soil: next_map()?,
+            soil_to_fertilizer: next_map()?,
+            fertilizer_to_water: next_map()?,
+            water_to_light: next_map()?,
+            light_to_temperature: next_map()?,
+            temperature_to_humidity: next_map()?,
+            humidity_to_location: next_map()?,
+            map_lines,
+        })
+    }
+
+    pub fn get_location_for_seed(&self, seed: u8) -> Option<&u8> {
+        self.seed_to_soil
+            .get(&seed)
+            .and_then(|soil| self.soil_to_fertilizer.get(soil))
+            .and_then(|fertilizer| self.fertilizer_to_water.get(fertilizer))
+            .and_then(|water| self.water_to_light.get(water))
+            .and_then(|light| self.light_to_temperature.get(light))
+            .and_then(|temperature| self.temperature_to_humidity.get(temperature))
+            .and_then(|humidity| self.humidity_to_location.get(humidity))
+    }
+
+    fn maps(&self) -> [&HashMap<u8, u8>; 7] {
+        [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temperature,
+            &self.temperature_to_humidity,
+            &self.humidity_to_location,
+        ]
+    }
+
+    /// Same lookup as `get_location_for_seed`, but keeps every intermediate value and the map line it comes from.
+    pub fn trace_seed(&self, seed: u8) -> Option<SeedTrace> {
+        let mut steps = vec![ConversionStep {
+            category: CATEGORIES[0],
+            value: seed,
+            matched_line: None,
+        }];
+        for (index, map) in self.maps().iter().enumerate() {
+            let source_value = steps.last().unwrap().value;
+            // later lines overwrite earlier ones when the almanac is parsed, so the last match wins
+            let matched_line = self.map_lines[index]
+                .iter()
+                .rev()
+                .find(|map_line| map_line.contains(source_value))
+                .map(|map_line| map_line.line);
+            steps.push(ConversionStep {
+                category: CATEGORIES[index + 1],
+                value: *map.get(&source_value)?,
+                matched_line,
+            });
+        }
+        Some(SeedTrace { steps })
+    }
+}
+
+///
+/// Build one map from its ranges, e.g. `50 98 2` maps 98 to 50 and 99 to 51.
+/// Values that no range covers map to themselves, and later ranges overwrite earlier ones.
+///
+fn build_map(map_lines: &[MapLine]) -> HashMap<u8, u8> {
+    let mut map: HashMap<u8, u8> = (0u8..100u8).map(|x| (x, x)).collect();
+    for map_line in map_lines {
+        // parse_map_lines already rejected the ranges that overflow
+        for offset in 0..map_line.len {
+            map.insert(map_line.source + offset, map_line.destination + offset);
+        }
+    }
+    map
+}
+
+pub fn sample() -> Example {
+    aoc::example!(5, "sample")
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Parsed = Almanac;
+
+    /// The almanac is validated first, so that every problem is reported with its line number.
+    /// The ranges it can still be built from are logged as warnings.
+    fn parse(input: &str) -> aoc::Result<Self::Parsed> {
+        match validate(input, Strictness::Lenient) {
+            Ok(warnings) => warnings
+                .iter()
+                .for_each(|warning| aoc::warn!("{}", warning)),
+            Err(errors) => {
+                let errors: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+                return Err(errors.join("\n").into());
+            }
+        }
+        Ok(Almanac::new(input.to_string())?)
+    }
+
+    /// Lowest location number that corresponds to any of the initial seed numbers.
+    fn part1(almanac: &Self::Parsed) -> aoc::Result<Answer> {
+        let locations = almanac
+            .seeds
+            .iter()
+            .map(|seed| {
+                almanac
+                    .get_location_for_seed(*seed)
+                    .copied()
+                    .ok_or_else(|| format!("seed {} is not in the almanac", seed))
+            })
+            .collect::<Result<Vec<u8>, String>>()?;
+        locations
+            .into_iter()
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| "the almanac has no seed".into())
+    }
+
+    fn part2(_almanac: &Self::Parsed) -> aoc::Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_sample_when_part1_then_35() {
+        let almanac = Day5::parse(&sample().input).unwrap();
+
+        assert_eq!(Day5::part1(&almanac).unwrap(), Answer::Number(35));
+    }
+
+    #[test]
+    fn given_malformed_almanac_when_parse_then_error() {
+        let input = sample().input.replace("50 98 2", "50 x 2");
+
+        assert!(Day5::parse(&input).is_err());
+    }
+
+    #[test]
+    fn given_almanacs_the_parser_cannot_build_when_parse_then_error() {
+        let inputs = [
+            sample()
+                .input
+                .replace("seeds: 79 14 55 13", "seeds: 3082872446 14 55 13"),
+            sample().input.replace("50 98 2", "0 250 10"),
+            sample()
+                .input
+                .split("\n\nwater-to-light map:")
+                .next()
+                .unwrap()
+                .to_string(),
+        ];
+
+        for input in inputs {
+            assert!(Day5::parse(&input).is_err(), "{}", input);
+            assert!(Almanac::new(input.clone()).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn given_value_outside_of_the_maps_when_part1_then_error() {
+        let input = sample()
+            .input
+            .replace("seeds: 79 14 55 13", "seeds: 79 14 55 98")
+            .replace("50 98 2", "150 98 2");
+        let almanac = Day5::parse(&input).unwrap();
+
+        assert_eq!(
+            Day5::part1(&almanac).unwrap_err().to_string(),
+            "seed 98 is not in the almanac"
+        );
+    }
+
+    #[test]
+    fn given_sample_seed_when_trace_then_every_category_matches_puzzle() {
+        let almanac = Almanac::new(sample().input).unwrap();
+
+        let trace = almanac.trace_seed(79).unwrap();
+
+        let values: Vec<u8> = trace.steps.iter().map(|step| step.value).collect();
+        assert_eq!(values, vec![79, 81, 81, 81, 74, 78, 78, 82]);
+        assert_eq!(
+            trace.steps.last().unwrap().value,
+            *almanac.get_location_for_seed(79).unwrap()
+        );
+    }
+
+    #[test]
+    fn given_sample_seed_when_trace_then_matched_lines_are_reported() {
+        let almanac = Almanac::new(sample().input).unwrap();
+
+        let trace = almanac.trace_seed(14).unwrap();
+
+        let lines: Vec<Option<usize>> = trace.steps.iter().map(|step| step.matched_line).collect();
+        // seed 14 has no seed-to-soil range, then soil 14 is covered by "39 0 15" at line 10
+        assert_eq!(
+            lines,
+            vec![
+                None,
+                None,
+                Some(10),
+                Some(13),
+                Some(20),
+                None,
+                Some(29),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn given_every_value_when_trace_then_matched_lines_give_the_map_values() {
+        // the first seed-to-soil range overlaps the second, so the later one wins in both
+        let input = sample().input.replace("50 98 2", "50 96 4");
+        let almanac = Almanac::new(input).unwrap();
+
+        for seed in 0..100 {
+            let trace = almanac.trace_seed(seed).unwrap();
+            for (index, pair) in trace.steps.windows(2).enumerate() {
+                let (source, step) = (pair[0].value, pair[1]);
+                let expected = match step.matched_line {
+                    Some(line) => {
+                        let map_line = almanac.map_lines[index]
+                            .iter()
+                            .find(|map_line| map_line.line == line)
+                            .unwrap();
+                        map_line.destination + (source - map_line.source)
+                    }
+                    None => source,
+                };
+                assert_eq!(step.value, expected, "seed {}: {:?}", seed, step);
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+/// Names of the categories a seed goes through, in conversion order.
+pub const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Headers of the maps, e.g. `seed-to-soil`, in the order the parser expects them.
+pub fn section_names() -> Vec<String> {
+    CATEGORIES
+        .windows(2)
+        .map(|pair| format!("{}-to-{}", pair[0], pair[1]))
+        .collect()
+}
+
+/// One range line of a category map, with its 1-based line number in the almanac.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MapLine {
+    pub line: usize,
+    pub destination: u8,
+    pub source: u8,
+    pub len: u8,
+}
+
+impl MapLine {
+    pub fn contains(&self, value: u8) -> bool {
+        value >= self.source && u16::from(value) < u16::from(self.source) + u16::from(self.len)
+    }
+}
+
+///
+/// Parse the range lines of one map, given with their 1-based line numbers, failing on values that
+/// do not fit in `u8` or ranges that overflow them.
+///
+pub fn parse_map_lines(header: &str, ranges: &[(usize, &str)]) -> Result<Vec<MapLine>, String> {
+    ranges
+        .iter()
+        .map(|(line, range)| {
+            let values: Vec<u8> = range
+                .split_whitespace()
+                .map(|s| s.parse::<u8>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| {
+                    format!(
+                        "range '{}' in '{}' is not made of values up to 255",
+                        range, header
+                    )
+                })?;
+            aoc::trace!("{} {:?}", header, values);
+            let [destination, source, len] = values[..] else {
+                return Err(format!("malformed range '{}' in '{}'", range, header));
+            };
+            if source.checked_add(len).is_none() || destination.checked_add(len).is_none() {
+                return Err(format!("range '{}' overflows in '{}'", range, header));
+            }
+            Ok(MapLine {
+                line: *line,
+                destination,
+                source,
+                len,
+            })
+        })
+        .collect()
+}
+
+/// The value of a seed in one category, and the map line that produced it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ConversionStep {
+    pub category: &'static str,
+    pub value: u8,
+    /// `None` for the seed itself and for values that no range covers, which map to themselves.
+    pub matched_line: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedTrace {
+    pub steps: Vec<ConversionStep>,
+}
+
+const COLUMN_WIDTH: usize = 16;
+
+///
+/// Render the traces as a table with a column per category, e.g.
+///
+/// ```text
+/// seed            soil            ...
+/// 79              81 (line 5)     ...
+/// ```
+///
+pub fn format_trace_table(traces: &[SeedTrace]) -> String {
+    let header: String = CATEGORIES
+        .iter()
+        .map(|category| format!("{:<width$}", category, width = COLUMN_WIDTH))
+        .collect();
+    let mut table = vec![header.trim_end().to_string()];
+    for trace in traces {
+        let row: String = trace
+            .steps
+            .iter()
+            .map(|step| {
+                let cell = match step.matched_line {
+                    Some(line) => format!("{} (line {})", step.value, line),
+                    None => step.value.to_string(),
+                };
+                format!("{:<width$}", cell, width = COLUMN_WIDTH)
+            })
+            .collect();
+        table.push(row.trim_end().to_string());
+    }
+    table.join("\n")
+}
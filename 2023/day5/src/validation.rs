@@ -0,0 +1,452 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::trace::section_names;
+
+/// How the validator surfaces the problems it finds in an almanac.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Strictness {
+    /// Ranges the parser survives, overlapping or empty ones, are reported as warnings, the others are errors.
+    Lenient,
+    /// Every problem is a hard error.
+    Strict,
+}
+
+/// A single problem found in the almanac. Line numbers are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// The line does not contain exactly three numbers.
+    MalformedLine { section: String, line: usize },
+    /// The range covers no value at all.
+    ZeroLengthRange { section: String, line: usize },
+    /// `source + len` or `destination + len` does not fit in the almanac values.
+    RangeOverflow { section: String, line: usize },
+    /// The source range overlaps with the one at `other_line`, which will be overwritten.
+    OverlappingRanges {
+        section: String,
+        line: usize,
+        other_line: usize,
+    },
+    /// The section header has already been declared at `first_line`.
+    DuplicateSection {
+        section: String,
+        line: usize,
+        first_line: usize,
+    },
+    /// A number does not fit in the almanac values.
+    ValueOutOfRange { section: String, line: usize },
+    /// The section is not the one expected at this point, the parser reads them in conversion order.
+    UnexpectedSection {
+        section: String,
+        line: usize,
+        expected: Option<String>,
+    },
+    /// The section never appears in the almanac.
+    MissingSection { section: String },
+    /// The almanac has no `seeds:` line.
+    MissingSeeds,
+    /// The line comes before the first section header, so it belongs to no map.
+    RangeOutsideSection { line: usize },
+}
+
+impl Diagnostic {
+    /// Problems the parser cannot build an almanac from, whatever the strictness.
+    fn is_always_error(&self) -> bool {
+        !matches!(
+            self,
+            Diagnostic::ZeroLengthRange { .. } | Diagnostic::OverlappingRanges { .. }
+        )
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::MalformedLine { section, line } => {
+                write!(f, "line {}: malformed range in '{}'", line, section)
+            }
+            Diagnostic::ZeroLengthRange { section, line } => {
+                write!(f, "line {}: zero-length range in '{}'", line, section)
+            }
+            Diagnostic::RangeOverflow { section, line } => {
+                write!(f, "line {}: range overflows in '{}'", line, section)
+            }
+            Diagnostic::OverlappingRanges {
+                section,
+                line,
+                other_line,
+            } => write!(
+                f,
+                "line {}: source range overlaps with line {} in '{}'",
+                line, other_line, section
+            ),
+            Diagnostic::DuplicateSection {
+                section,
+                line,
+                first_line,
+            } => write!(
+                f,
+                "line {}: section '{}' already declared at line {}",
+                line, section, first_line
+            ),
+            Diagnostic::ValueOutOfRange { section, line } => write!(
+                f,
+                "line {}: value above {} in '{}'",
+                line, MAX_VALUE, section
+            ),
+            Diagnostic::UnexpectedSection {
+                section,
+                line,
+                expected: Some(expected),
+            } => write!(
+                f,
+                "line {}: unexpected section '{}', expected '{}'",
+                line, section, expected
+            ),
+            Diagnostic::UnexpectedSection {
+                section,
+                line,
+                expected: None,
+            } => write!(
+                f,
+                "line {}: unexpected section '{}' after the last map",
+                line, section
+            ),
+            Diagnostic::MissingSection { section } => {
+                write!(f, "missing section '{}'", section)
+            }
+            Diagnostic::MissingSeeds => write!(f, "missing 'seeds:' line"),
+            Diagnostic::RangeOutsideSection { line } => {
+                write!(f, "line {}: range outside of any map section", line)
+            }
+        }
+    }
+}
+
+/// The almanac stores every category value as `u8`, and builds each range as `start..start + len`.
+const MAX_VALUE: u64 = u8::MAX as u64;
+
+struct Range {
+    line: usize,
+    source: u64,
+    len: u64,
+}
+
+impl Range {
+    fn overlaps(&self, other: &Range) -> bool {
+        self.source < other.source.saturating_add(other.len)
+            && other.source < self.source.saturating_add(self.len)
+    }
+}
+
+///
+/// Check the maps of an almanac for ranges that the parser would silently accept, or could not build.
+///
+/// Returns the warnings found when the almanac is usable, or every diagnostic when at least one of them
+/// is an error for the given strictness.
+///
+pub fn validate(input: &str, strictness: Strictness) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+    let mut diagnostics = vec![];
+    let sections = section_names();
+    let mut declared_sections: HashMap<&str, usize> = HashMap::new();
+    let mut current_section: Option<&str> = None;
+    let mut has_seeds = false;
+    let mut ranges: Vec<Range> = vec![];
+
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(seeds) = line.strip_prefix("seeds:") {
+            has_seeds = true;
+            let seeds: Vec<Option<u64>> = seeds
+                .split_whitespace()
+                .map(|s| s.parse::<u64>().ok())
+                .collect();
+            let section = "seeds".to_string();
+            if seeds.is_empty() || seeds.contains(&None) {
+                diagnostics.push(Diagnostic::MalformedLine {
+                    section,
+                    line: line_number,
+                });
+            } else if seeds.iter().flatten().any(|seed| *seed > MAX_VALUE) {
+                diagnostics.push(Diagnostic::ValueOutOfRange {
+                    section,
+                    line: line_number,
+                });
+            }
+            continue;
+        }
+        if let Some(section) = line.strip_suffix(" map:") {
+            if let Some(first_line) = declared_sections.get(section) {
+                diagnostics.push(Diagnostic::DuplicateSection {
+                    section: section.to_string(),
+                    line: line_number,
+                    first_line: *first_line,
+                });
+            } else {
+                let expected = sections.get(declared_sections.len());
+                if expected.map(String::as_str) != Some(section) {
+                    diagnostics.push(Diagnostic::UnexpectedSection {
+                        section: section.to_string(),
+                        line: line_number,
+                        expected: expected.cloned(),
+                    });
+                }
+                declared_sections.insert(section, line_number);
+            }
+            current_section = Some(section);
+            ranges.clear();
+            continue;
+        }
+
+        let Some(section) = current_section.map(str::to_string) else {
+            diagnostics.push(Diagnostic::RangeOutsideSection { line: line_number });
+            continue;
+        };
+        let values: Vec<u64> = line
+            .split_whitespace()
+            .map_while(|s| s.parse::<u64>().ok())
+            .collect();
+        if values.len() != 3 || line.split_whitespace().count() != 3 {
+            diagnostics.push(Diagnostic::MalformedLine {
+                section,
+                line: line_number,
+            });
+            continue;
+        }
+        let (destination, source, len) = (values[0], values[1], values[2]);
+
+        if values.iter().any(|value| *value > MAX_VALUE) {
+            diagnostics.push(Diagnostic::ValueOutOfRange {
+                section,
+                line: line_number,
+            });
+            continue;
+        }
+        if len == 0 {
+            diagnostics.push(Diagnostic::ZeroLengthRange {
+                section,
+                line: line_number,
+            });
+            continue;
+        }
+        if source + len > MAX_VALUE || destination + len > MAX_VALUE {
+            diagnostics.push(Diagnostic::RangeOverflow {
+                section: section.clone(),
+                line: line_number,
+            });
+        }
+
+        let range = Range {
+            line: line_number,
+            source,
+            len,
+        };
+        if let Some(other) = ranges.iter().find(|other| other.overlaps(&range)) {
+            diagnostics.push(Diagnostic::OverlappingRanges {
+                section,
+                line: line_number,
+                other_line: other.line,
+            });
+        }
+        ranges.push(range);
+    }
+
+    if !has_seeds {
+        diagnostics.push(Diagnostic::MissingSeeds);
+    }
+    diagnostics.extend(
+        sections
+            .iter()
+            .filter(|section| !declared_sections.contains_key(section.as_str()))
+            .map(|section| Diagnostic::MissingSection {
+                section: section.clone(),
+            }),
+    );
+
+    let has_errors = diagnostics
+        .iter()
+        .any(|d| strictness == Strictness::Strict || d.is_always_error());
+    if has_errors {
+        Err(diagnostics)
+    } else {
+        Ok(diagnostics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Almanac with the given seed-to-soil ranges from line 4, and every other map empty.
+    fn almanac(seed_to_soil: &str) -> String {
+        let other_maps: Vec<String> = section_names()
+            .iter()
+            .skip(1)
+            .map(|section| format!("{} map:", section))
+            .collect();
+        format!(
+            "seeds: 79 14\n\nseed-to-soil map:\n{}\n\n{}",
+            seed_to_soil,
+            other_maps.join("\n\n")
+        )
+    }
+
+    #[test]
+    fn given_valid_almanac_when_validate_then_no_diagnostics() {
+        let input = almanac("50 98 2\n52 50 48");
+
+        assert_eq!(validate(&input, Strictness::Strict), Ok(vec![]));
+    }
+
+    #[test]
+    fn given_overlapping_ranges_when_validate_lenient_then_warning() {
+        let input = almanac("50 98 2\n52 90 10");
+
+        let warnings = validate(&input, Strictness::Lenient).unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![Diagnostic::OverlappingRanges {
+                section: "seed-to-soil".to_string(),
+                line: 5,
+                other_line: 4,
+            }]
+        );
+    }
+
+    #[test]
+    fn given_overlapping_ranges_when_validate_strict_then_error() {
+        let input = almanac("50 98 2\n52 90 10");
+
+        assert!(validate(&input, Strictness::Strict).is_err());
+    }
+
+    #[test]
+    fn given_adjacent_ranges_when_validate_then_no_overlap() {
+        let input = almanac("50 10 5\n0 15 5");
+
+        assert_eq!(validate(&input, Strictness::Strict), Ok(vec![]));
+    }
+
+    #[test]
+    fn given_empty_range_when_validate_lenient_then_warning() {
+        let input = almanac("5 5 0");
+
+        let warnings = validate(&input, Strictness::Lenient).unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![Diagnostic::ZeroLengthRange {
+                section: "seed-to-soil".to_string(),
+                line: 4,
+            }]
+        );
+    }
+
+    #[test]
+    fn given_overflowing_ranges_when_validate_lenient_then_error() {
+        // the last range ends exactly on 255, which `u8` can still hold
+        let input = almanac("0 250 10\n250 0 6\n250 10 5");
+
+        let errors = validate(&input, Strictness::Lenient).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                Diagnostic::RangeOverflow {
+                    section: "seed-to-soil".to_string(),
+                    line: 4,
+                },
+                Diagnostic::RangeOverflow {
+                    section: "seed-to-soil".to_string(),
+                    line: 5,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn given_values_above_u8_when_validate_lenient_then_error() {
+        let input = almanac("0 300 1").replace("seeds: 79 14", "seeds: 3082872446 14");
+
+        let errors = validate(&input, Strictness::Lenient).unwrap_err();
+
+        let messages: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "line 1: value above 255 in 'seeds'",
+                "line 4: value above 255 in 'seed-to-soil'"
+            ]
+        );
+    }
+
+    #[test]
+    fn given_duplicate_section_when_validate_lenient_then_error() {
+        let input = almanac("0 1 1").replace(
+            "soil-to-fertilizer map:",
+            "seed-to-soil map:\n2 3 1\n\nsoil-to-fertilizer map:",
+        );
+
+        let errors = validate(&input, Strictness::Lenient).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![Diagnostic::DuplicateSection {
+                section: "seed-to-soil".to_string(),
+                line: 6,
+                first_line: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn given_missing_sections_when_validate_lenient_then_error() {
+        let input = "seed-to-soil map:\n50 98 2\n\nfertilizer-to-water map:\n0 1 1";
+
+        let errors = validate(input, Strictness::Lenient).unwrap_err();
+
+        let messages: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "line 4: unexpected section 'fertilizer-to-water', expected 'soil-to-fertilizer'",
+                "missing 'seeds:' line",
+                "missing section 'soil-to-fertilizer'",
+                "missing section 'water-to-light'",
+                "missing section 'light-to-temperature'",
+                "missing section 'temperature-to-humidity'",
+                "missing section 'humidity-to-location'",
+            ]
+        );
+    }
+
+    #[test]
+    fn given_malformed_line_when_validate_lenient_then_error() {
+        let input = almanac("0 1");
+
+        let errors = validate(&input, Strictness::Lenient).unwrap_err();
+
+        assert_eq!(
+            errors[0].to_string(),
+            "line 4: malformed range in 'seed-to-soil'"
+        );
+    }
+
+    #[test]
+    fn given_range_before_any_section_when_validate_lenient_then_error() {
+        let input = format!("1 2 3\n{}", almanac("50 98 2"));
+
+        let errors = validate(&input, Strictness::Lenient).unwrap_err();
+
+        assert_eq!(errors, vec![Diagnostic::RangeOutsideSection { line: 1 }]);
+        assert_eq!(
+            errors[0].to_string(),
+            "line 1: range outside of any map section"
+        );
+    }
+}
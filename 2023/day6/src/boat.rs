@@ -0,0 +1,221 @@
+use crate::Race;
+
+///
+/// How a toy boat reacts to the button.
+///
+/// Holding the button for `h` milliseconds gives the boat a speed of `initial_speed + acceleration * h`,
+/// which it keeps for the rest of the race. The puzzle boat is the `Default` one.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BoatPhysics {
+    /// Speed gained for each millisecond the button is held, in mm/ms.
+    pub acceleration: u64,
+    /// Speed of the boat when the button is released immediately, in mm/ms.
+    pub initial_speed: u64,
+    /// The button cannot be held longer than this, if set.
+    pub max_charge_time: Option<u64>,
+}
+
+impl Default for BoatPhysics {
+    fn default() -> Self {
+        BoatPhysics {
+            acceleration: 1,
+            initial_speed: 0,
+            max_charge_time: None,
+        }
+    }
+}
+
+/// The hold time that makes the boat go the farthest, with the distance it travels.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OptimalHold {
+    pub hold_time: u64,
+    pub distance: u128,
+}
+
+impl BoatPhysics {
+    /// Distance travelled in a race of `race_time` ms, saturating at `u128::MAX`.
+    pub fn distance(&self, race_time: u64, hold_time: u64) -> u128 {
+        if hold_time >= race_time {
+            return 0;
+        }
+        let speed = (self.acceleration as u128)
+            .saturating_mul(hold_time as u128)
+            .saturating_add(self.initial_speed as u128);
+        speed.saturating_mul((race_time - hold_time) as u128)
+    }
+
+    fn longest_hold(&self, race: &Race) -> u64 {
+        self.max_charge_time
+            .map_or(race.time, |max_charge_time| max_charge_time.min(race.time))
+    }
+
+    ///
+    /// Find the hold time giving the longest distance. When several hold times tie, the shortest is returned.
+    ///
+    /// The distance is a concave function of the hold time, so the optimum is the first hold time
+    /// after which the distance stops growing, found with a binary search.
+    ///
+    pub fn optimal_hold(&self, race: &Race) -> OptimalHold {
+        let mut low = 0;
+        let mut high = self.longest_hold(race);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.distance(race.time, middle + 1) > self.distance(race.time, middle) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        OptimalHold {
+            hold_time: low,
+            distance: self.distance(race.time, low),
+        }
+    }
+
+    ///
+    /// Count the hold times that beat the record of the race.
+    ///
+    /// The winning hold times are a contiguous interval around the optimal one: the distance grows
+    /// before it and shrinks after it, so both ends are found with a binary search.
+    ///
+    pub fn count_ways_to_beat_record(&self, race: &Race) -> u64 {
+        let record = race.record_distance as u128;
+        let optimal = self.optimal_hold(race);
+        if optimal.distance <= record {
+            return 0;
+        }
+
+        // first hold time beating the record, in [0, optimal]
+        let (mut low, mut high) = (0, optimal.hold_time);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.distance(race.time, middle) > record {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        let first = low;
+
+        // last hold time beating the record, in [optimal, longest hold]
+        let (mut low, mut high) = (optimal.hold_time, self.longest_hold(race));
+        while low < high {
+            let middle = low + (high - low).div_ceil(2);
+            if self.distance(race.time, middle) > record {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        let last = low;
+
+        last - first + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_default_physics_when_count_then_match_puzzle() {
+        let physics = BoatPhysics::default();
+
+        for (time, record_distance, expected) in [(7, 9, 4), (15, 40, 8), (30, 200, 9)] {
+            let race = Race {
+                time,
+                record_distance,
+            };
+            assert_eq!(physics.count_ways_to_beat_record(&race), expected);
+        }
+    }
+
+    #[test]
+    fn given_default_physics_when_count_then_agree_with_closed_form() {
+        let physics = BoatPhysics::default();
+
+        for time in 0..80 {
+            for record_distance in 0..=(time * time / 4 + 1) {
+                let race = Race {
+                    time,
+                    record_distance,
+                };
+                assert_eq!(
+                    physics.count_ways_to_beat_record(&race),
+                    race.calculate_number_of_ways_to_beat_record_closed_form()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn given_default_physics_when_optimal_hold_then_half_the_race() {
+        let race = Race {
+            time: 7,
+            record_distance: 9,
+        };
+
+        let optimal = BoatPhysics::default().optimal_hold(&race);
+
+        assert_eq!(
+            optimal,
+            OptimalHold {
+                hold_time: 3,
+                distance: 12
+            }
+        );
+    }
+
+    #[test]
+    fn given_initial_speed_and_acceleration_when_count_then_agree_with_brute_force() {
+        let race = Race {
+            time: 40,
+            record_distance: 300,
+        };
+
+        for acceleration in 0..4 {
+            for initial_speed in 0..12 {
+                for max_charge_time in [None, Some(0), Some(5), Some(25), Some(100)] {
+                    let physics = BoatPhysics {
+                        acceleration,
+                        initial_speed,
+                        max_charge_time,
+                    };
+                    let longest_hold = max_charge_time.unwrap_or(race.time).min(race.time);
+                    let expected = (0..=longest_hold)
+                        .filter(|hold| physics.distance(race.time, *hold) > 300)
+                        .count() as u64;
+                    let best = (0..=longest_hold)
+                        .map(|hold| physics.distance(race.time, hold))
+                        .max()
+                        .unwrap();
+
+                    assert_eq!(physics.count_ways_to_beat_record(&race), expected);
+                    assert_eq!(physics.optimal_hold(&race).distance, best);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn given_max_charge_time_when_optimal_hold_then_capped() {
+        let physics = BoatPhysics {
+            max_charge_time: Some(10),
+            ..BoatPhysics::default()
+        };
+        let race = Race {
+            time: 30,
+            record_distance: 200,
+        };
+
+        assert_eq!(
+            physics.optimal_hold(&race),
+            OptimalHold {
+                hold_time: 10,
+                distance: 200
+            }
+        );
+        assert_eq!(physics.count_ways_to_beat_record(&race), 0);
+    }
+}
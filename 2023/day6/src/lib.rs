@@ -0,0 +1,412 @@
+use aoc::example::Example;
+use aoc::{Answer, Solution};
+
+pub use crate::boat::BoatPhysics;
+
+mod boat;
+
+/*
+
+Exercise https://adventofcode.com/2023/day/6
+
+--- Day 6: Wait For It ---
+The ferry quickly brings you across Island Island. After asking around, you discover that there is indeed normally a large pile of sand somewhere near here, but you don't see anything besides lots of water and the small island where the ferry has docked.
+
+As you try to figure out what to do next, you notice a poster on a wall near the ferry dock. "Boat races! Open to the public! Grand prize is an all-expenses-paid trip to Desert Island!" That must be where the sand comes from! Best of all, the boat races are starting in just a few minutes.
+
+You manage to sign up as a competitor in the boat races just in time. The organizer explains that it's not really a traditional race - instead, you will get a fixed amount of time during which your boat has to travel as far as it can, and you win if your boat goes the farthest.
+
+As part of signing up, you get a sheet of paper (your puzzle input) that lists the time allowed for each race and also the best distance ever recorded in that race. To guarantee you win the grand prize, you need to make sure you go farther in each race than the current record holder.
+
+The organizer brings you over to the area where the boat races are held. The boats are much smaller than you expected - they're actually toy boats, each with a big button on top. Holding down the button charges the boat, and releasing the button allows the boat to move. Boats move faster if their button was held longer, but time spent holding the button counts against the total race time. You can only hold the button at the start of the race, and boats don't move until the button is released.
+
+For example:
+
+Time:      7  15   30
+Distance:  9  40  200
+This document describes three races:
+
+The first race lasts 7 milliseconds. The record distance in this race is 9 millimeters.
+The second race lasts 15 milliseconds. The record distance in this race is 40 millimeters.
+The third race lasts 30 milliseconds. The record distance in this race is 200 millimeters.
+Your toy boat has a starting speed of zero millimeters per millisecond. For each whole millisecond you spend at the beginning of the race holding down the button, the boat's speed increases by one millimeter per millisecond.
+
+So, because the first race lasts 7 milliseconds, you only have a few options:
+
+Don't hold the button at all (that is, hold it for 0 milliseconds) at the start of the race. The boat won't move; it will have traveled 0 millimeters by the end of the race.
+Hold the button for 1 millisecond at the start of the race. Then, the boat will travel at a speed of 1 millimeter per millisecond for 6 milliseconds, reaching a total distance traveled of 6 millimeters.
+Hold the button for 2 milliseconds, giving the boat a speed of 2 millimeters per millisecond. It will then get 5 milliseconds to move, reaching a total distance of 10 millimeters.
+Hold the button for 3 milliseconds. After its remaining 4 milliseconds of travel time, the boat will have gone 12 millimeters.
+Hold the button for 4 milliseconds. After its remaining 3 milliseconds of travel time, the boat will have gone 12 millimeters.
+Hold the button for 5 milliseconds, causing the boat to travel a total of 10 millimeters.
+Hold the button for 6 milliseconds, causing the boat to travel a total of 6 millimeters.
+Hold the button for 7 milliseconds. That's the entire duration of the race. You never let go of the button. The boat can't move until you let go of the button. Please make sure you let go of the button so the boat gets to move. 0 millimeters.
+Since the current record for this race is 9 millimeters, there are actually 4 different ways you could win: you could hold the button for 2, 3, 4, or 5 milliseconds at the start of the race.
+
+In the second race, you could hold the button for at least 4 milliseconds and at most 11 milliseconds and beat the record, a total of 8 different ways to win.
+
+In the third race, you could hold the button for at least 11 milliseconds and no more than 19 milliseconds and still beat the record, a total of 9 ways you could win.
+
+To see how much margin of error you have, determine the number of ways you can beat the record in each race; in this example, if you multiply these values together, you get 288 (4 * 8 * 9).
+
+Determine the number of ways you could beat the record in each race. What do you get if you multiply these numbers together?
+
+**/
+
+/*
+--- Part Two ---
+As the race is about to start, you realize the piece of paper with race times and record distances you got
+earlier actually just has very bad kerning. There's really only one race - ignore the spaces between the numbers
+on each line.
+
+So, the example from before:
+
+Time:      7  15   30
+Distance:  9  40  200
+...now instead means this:
+
+Time:      71530
+Distance:  940200
+Now, you have to figure out how many ways there are to win this single race. In this example, the race lasts for
+71530 milliseconds and the record distance you need to beat is 940200 millimeters. You could hold the button
+anywhere from 14 to 71516 milliseconds and beat the record, a total of 71503 ways!
+
+How many ways can you beat the record in this one much longer race?
+**/
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Race {
+    time: u64,
+    record_distance: u64,
+}
+
+impl Race {
+    pub fn calculate_number_of_ways_to_beat_record(&self) -> u64 {
+        let mut number_of_ways = 0;
+
+        // I exclude the extremes because if I keep the button pressed for 0 milliseconds, the boat won't move,
+        // and if I keep it pressed for the entire time, the time will expire and the boat has not moved.
+        for i in 1..self.time {
+            let time_pressed = i;
+            let speed = time_pressed;
+            let time_remaining = self.time - time_pressed;
+            let distance = speed * time_remaining;
+            if distance > self.record_distance {
+                number_of_ways += 1;
+            }
+        }
+
+        number_of_ways
+    }
+
+    ///
+    /// Same result as `calculate_number_of_ways_to_beat_record`, in constant time.
+    ///
+    /// Holding the button for `h` milliseconds beats the record when `h * (time - h) > record_distance`,
+    /// i.e. when `h` lies strictly between the roots of `h^2 - time * h + record_distance = 0`.
+    /// The roots are approximated with an integer square root and then corrected by checking the
+    /// inequality directly, so there is no floating point error even for very large races.
+    ///
+    pub fn calculate_number_of_ways_to_beat_record_closed_form(&self) -> u64 {
+        let time = self.time as u128;
+        let record = self.record_distance as u128;
+        let beats_record = |hold: u128| hold * (time - hold) > record;
+
+        let discriminant = match (time * time).checked_sub(4 * record) {
+            Some(discriminant) if discriminant > 0 => discriminant,
+            _ => return 0,
+        };
+
+        // smallest hold time that beats the record, starting from the approximated lower root
+        let mut lowest = (time - discriminant.isqrt()) / 2;
+        while lowest > 0 && beats_record(lowest - 1) {
+            lowest -= 1;
+        }
+        while lowest <= time / 2 && !beats_record(lowest) {
+            lowest += 1;
+        }
+        if lowest > time / 2 {
+            return 0;
+        }
+
+        // the distance is symmetric around time / 2, so the highest hold time mirrors the lowest one
+        let highest = time - lowest;
+        (highest - lowest + 1) as u64
+    }
+}
+
+///
+/// Parse one race per column.
+///
+/// # Expected format:
+/// ```text
+/// Time:      7  15   30
+/// Distance:  9  40  200
+/// ```
+///
+fn parse_races(input: &str) -> Result<Vec<Race>, &'static str> {
+    let times = parse_numbers(find_line(input, "Time:")?)?;
+    let distances = parse_numbers(find_line(input, "Distance:")?)?;
+    if times.len() != distances.len() {
+        return Err("Time and Distance lines must have the same number of values");
+    }
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, record_distance)| Race {
+            time,
+            record_distance,
+        })
+        .collect())
+}
+
+///
+/// Parse the same format as `parse_races`, ignoring the spaces between the numbers of each line,
+/// so that the whole sheet describes a single race.
+///
+fn parse_single_race(input: &str) -> Result<Race, &'static str> {
+    let time = parse_kerned_number(find_line(input, "Time:")?)?;
+    let record_distance = parse_kerned_number(find_line(input, "Distance:")?)?;
+    Ok(Race {
+        time,
+        record_distance,
+    })
+}
+
+fn find_line<'a>(input: &'a str, label: &str) -> Result<&'a str, &'static str> {
+    input
+        .lines()
+        .find_map(|line| line.trim().strip_prefix(label))
+        .ok_or("Missing Time or Distance line")
+}
+
+fn parse_numbers(values: &str) -> Result<Vec<u64>, &'static str> {
+    values
+        .split_whitespace()
+        .map(|value| value.parse::<u64>().map_err(|_| "Invalid number"))
+        .collect()
+}
+
+fn parse_kerned_number(values: &str) -> Result<u64, &'static str> {
+    values
+        .split_whitespace()
+        .collect::<String>()
+        .parse::<u64>()
+        .map_err(|_| "Invalid number")
+}
+
+pub fn sample() -> Example {
+    aoc::example!(6, "sample")
+}
+
+/// The races of the sheet, and the single race found by ignoring the spaces between the numbers.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RaceSheet {
+    pub races: Vec<Race>,
+    pub single_race: Race,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Parsed = RaceSheet;
+
+    fn parse(input: &str) -> aoc::Result<Self::Parsed> {
+        Ok(RaceSheet {
+            races: parse_races(input)?,
+            single_race: parse_single_race(input)?,
+        })
+    }
+
+    /// Product of the number of ways to beat the record of each race.
+    fn part1(sheet: &Self::Parsed) -> aoc::Result<Answer> {
+        let multiplied_ways: u64 = sheet
+            .races
+            .iter()
+            .map(|race| race.calculate_number_of_ways_to_beat_record_closed_form())
+            .product();
+        Ok(multiplied_ways.into())
+    }
+
+    fn part2(sheet: &Self::Parsed) -> aoc::Result<Answer> {
+        Ok(sheet
+            .single_race
+            .calculate_number_of_ways_to_beat_record_closed_form()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::test_util::Lcg;
+
+    use super::*;
+
+    #[test]
+    fn given_sample_when_solve_then_288_and_71503() {
+        let sheet = Day6::parse(&sample().input).unwrap();
+
+        assert_eq!(Day6::part1(&sheet).unwrap(), Answer::Number(288));
+        assert_eq!(Day6::part2(&sheet).unwrap(), Answer::Number(71503));
+    }
+
+    #[test]
+    fn given_sample_races_when_closed_form_then_match_puzzle() {
+        let ways: Vec<u64> = [(7, 9), (15, 40), (30, 200)]
+            .iter()
+            .map(|&(time, record_distance)| {
+                Race {
+                    time,
+                    record_distance,
+                }
+                .calculate_number_of_ways_to_beat_record_closed_form()
+            })
+            .collect();
+
+        assert_eq!(ways, vec![4, 8, 9]);
+    }
+
+    #[test]
+    fn given_record_on_the_vertex_when_closed_form_then_no_way() {
+        let race = Race {
+            time: 10,
+            record_distance: 25,
+        };
+
+        assert_eq!(
+            race.calculate_number_of_ways_to_beat_record_closed_form(),
+            0
+        );
+    }
+
+    #[test]
+    fn given_huge_race_when_closed_form_then_exact() {
+        // part 2 of the sample: a single race of 71530 ms with record 940200
+        let race = Race {
+            time: 71530,
+            record_distance: 940200,
+        };
+
+        assert_eq!(
+            race.calculate_number_of_ways_to_beat_record_closed_form(),
+            71503
+        );
+    }
+
+    #[test]
+    fn given_large_values_when_closed_form_then_no_overflow() {
+        let time = u32::MAX as u64;
+        let race = Race {
+            time,
+            record_distance: 0,
+        };
+
+        assert_eq!(
+            race.calculate_number_of_ways_to_beat_record_closed_form(),
+            time - 1
+        );
+    }
+
+    #[test]
+    fn property_closed_form_agrees_with_brute_force_on_small_races() {
+        for time in 0..120 {
+            for record_distance in 0..=(time * time / 4 + 1) {
+                let race = Race {
+                    time,
+                    record_distance,
+                };
+                assert_eq!(
+                    race.calculate_number_of_ways_to_beat_record_closed_form(),
+                    race.calculate_number_of_ways_to_beat_record(),
+                    "time {} record {}",
+                    time,
+                    record_distance
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn property_closed_form_agrees_with_brute_force_on_random_races() {
+        let mut rng = Lcg::new(42);
+        for _ in 0..500 {
+            let time = rng.next(20_000);
+            let record_distance = rng.next(time * time / 4 + 2);
+            let race = Race {
+                time,
+                record_distance,
+            };
+            assert_eq!(
+                race.calculate_number_of_ways_to_beat_record_closed_form(),
+                race.calculate_number_of_ways_to_beat_record(),
+                "time {} record {}",
+                time,
+                record_distance
+            );
+        }
+    }
+
+    #[test]
+    fn given_sample_input_when_parse_races_then_ok() {
+        let races = parse_races(&sample().input).unwrap();
+
+        assert_eq!(
+            races,
+            vec![
+                Race {
+                    time: 7,
+                    record_distance: 9
+                },
+                Race {
+                    time: 15,
+                    record_distance: 40
+                },
+                Race {
+                    time: 30,
+                    record_distance: 200
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn given_sample_input_when_parse_single_race_then_digits_are_concatenated() {
+        let race = parse_single_race(&sample().input).unwrap();
+
+        assert_eq!(
+            race,
+            Race {
+                time: 71530,
+                record_distance: 940200
+            }
+        );
+    }
+
+    #[test]
+    fn given_real_sized_input_when_parse_single_race_then_fits_u64() {
+        let input = "Time:        44     89     96     91\nDistance:   277   1136   1890   1768";
+
+        let race = parse_single_race(input).unwrap();
+
+        assert_eq!(race.time, 44899691);
+        assert_eq!(race.record_distance, 277113618901768);
+        assert_eq!(
+            race.calculate_number_of_ways_to_beat_record_closed_form(),
+            30125202
+        );
+    }
+
+    #[test]
+    fn given_mismatched_lines_when_parse_races_then_error() {
+        let input = "Time: 7 15\nDistance: 9";
+
+        assert!(parse_races(input).is_err());
+    }
+
+    #[test]
+    fn given_missing_line_when_parse_races_then_error() {
+        assert!(parse_races("Time: 7 15").is_err());
+    }
+}
@@ -0,0 +1,615 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use aoc::example::Example;
+use aoc::{Answer, Solution};
+
+pub use crate::report::{Report, Standing};
+pub use crate::rules::{ruleset_from_name, JokerRules, Ruleset, StandardRules};
+
+mod report;
+mod rules;
+
+/**
+
+Exercise https://adventofcode.com/2023/day/7
+
+
+**/
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Type {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    /// Only used by rulesets that recognize straights, e.g. `PokerRules`.
+    Straight,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, Copy, Ord, PartialOrd, Eq, PartialEq, Hash, Clone)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl TryFrom<char> for Card {
+    type Error = HandParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Card::Two),
+            '3' => Ok(Card::Three),
+            '4' => Ok(Card::Four),
+            '5' => Ok(Card::Five),
+            '6' => Ok(Card::Six),
+            '7' => Ok(Card::Seven),
+            '8' => Ok(Card::Eight),
+            '9' => Ok(Card::Nine),
+            'T' => Ok(Card::Ten),
+            'J' => Ok(Card::Jack),
+            'Q' => Ok(Card::Queen),
+            'K' => Ok(Card::King),
+            'A' => Ok(Card::Ace),
+            _ => Err(HandParseError::InvalidCard(value)),
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Jack => 'J',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A',
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandParseError {
+    /// The hand does not have exactly 5 cards, holds the number of cards found.
+    WrongNumberOfCards(usize),
+    InvalidCard(char),
+    MissingBid,
+    InvalidBid(String),
+}
+
+impl fmt::Display for HandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandParseError::WrongNumberOfCards(count) => {
+                write!(f, "hand must consist of 5 cards, found {}", count)
+            }
+            HandParseError::InvalidCard(card) => write!(f, "invalid card '{}'", card),
+            HandParseError::MissingBid => write!(f, "missing bid"),
+            HandParseError::InvalidBid(bid) => write!(f, "invalid bid '{}'", bid),
+        }
+    }
+}
+
+impl std::error::Error for HandParseError {}
+
+#[derive(Clone)]
+pub struct Hand {
+    cards: [Card; 5],
+    bid: u32,
+    /// Type under the standard rules, computed once since every comparison needs it.
+    hand_type: Type,
+}
+
+impl Hand {
+    pub fn new(cards_as_string: &str, bid: u32) -> Result<Self, HandParseError> {
+        let cards: [Card; 5] = cards_as_string
+            .chars()
+            .map(Card::try_from)
+            .collect::<Result<Vec<Card>, HandParseError>>()?
+            .try_into()
+            .map_err(|cards: Vec<Card>| HandParseError::WrongNumberOfCards(cards.len()))?;
+        let hand_type = StandardRules.classify(&cards);
+        Ok(Hand {
+            cards,
+            bid,
+            hand_type,
+        })
+    }
+
+    pub fn hand_type(&self) -> Type {
+        self.hand_type
+    }
+}
+
+// The cached type is left out: it only holds under the standard rules, see `Game::classify_and_rank`.
+impl fmt::Debug for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hand")
+            .field("cards", &self.cards)
+            .field("bid", &self.bid)
+            .finish()
+    }
+}
+
+// This makes it possible to print the cards of a Hand with {}, e.g. `32T3K`.
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cards.iter().try_for_each(|card| write!(f, "{}", card))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hand_type
+            .cmp(&other.hand_type)
+            .then_with(|| StandardRules.compare_cards(&self.cards, &other.cards))
+    }
+}
+
+impl Eq for Hand {}
+
+// Two hands are equal when they hold the same cards in the same order, whatever their bid,
+// so that equality and hashing agree with `Ord`.
+impl PartialEq<Self> for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cards == other.cards
+    }
+}
+
+impl Hash for Hand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cards.hash(state);
+    }
+}
+
+impl PartialOrd<Self> for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+///
+/// # Expected format:
+/// ```text
+/// 32T3K 765
+/// ```
+///
+impl FromStr for Hand {
+    type Err = HandParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut parts = line.split_whitespace();
+        let cards = parts.next().unwrap_or_default();
+        let bid = parts.next().ok_or(HandParseError::MissingBid)?;
+        if parts.next().is_some() {
+            return Err(HandParseError::InvalidBid(
+                line.trim().trim_start_matches(cards).trim().to_string(),
+            ));
+        }
+        let bid = bid
+            .parse::<u32>()
+            .map_err(|_| HandParseError::InvalidBid(bid.to_string()))?;
+        Hand::new(cards, bid)
+    }
+}
+
+/// A hand that could not be parsed, with its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameParseError {
+    pub line: usize,
+    pub error: HandParseError,
+}
+
+impl fmt::Display for GameParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl std::error::Error for GameParseError {}
+
+pub struct Game {
+    pub hands: Vec<Hand>,
+    rules: Box<dyn Ruleset>,
+}
+
+impl Game {
+    pub fn new(hands: Vec<Hand>) -> Self {
+        Self::with_rules(hands, Box::new(StandardRules))
+    }
+
+    pub fn with_rules(hands: Vec<Hand>, rules: Box<dyn Ruleset>) -> Self {
+        Game { hands, rules }
+    }
+
+    /// Hands sorted from the weakest to the strongest, each one classified only once.
+    pub fn rank(&self) -> Vec<&Hand> {
+        self.classify_and_rank()
+            .into_iter()
+            .map(|(_, hand)| hand)
+            .collect()
+    }
+
+    fn classify_and_rank(&self) -> Vec<(Type, &Hand)> {
+        let mut classified_hands: Vec<(Type, &Hand)> = self
+            .hands
+            .iter()
+            .map(|hand| (self.rules.classify(&hand.cards), hand))
+            .collect();
+        classified_hands.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then_with(|| self.rules.compare_cards(&a.1.cards, &b.1.cards))
+        });
+        classified_hands
+    }
+
+    /// Rank, type, bid and winnings of every hand, from the weakest to the strongest.
+    pub fn report(&self) -> Report {
+        let standings = self
+            .classify_and_rank()
+            .into_iter()
+            .enumerate()
+            .map(|(index, (hand_type, hand))| Standing {
+                rank: index + 1,
+                cards: hand.to_string(),
+                hand_type,
+                bid: hand.bid,
+                winnings: hand.bid * (index + 1) as u32,
+            })
+            .collect();
+        Report { standings }
+    }
+
+    pub fn play(&self) -> u32 {
+        let sorted_hands = self.classify_and_rank();
+        aoc::debug!("Sorted hands: {:?}", sorted_hands);
+        sorted_hands
+            .iter()
+            .enumerate()
+            .map(|(index, (_, hand))| {
+                aoc::trace!("hand with bid {} multiply rank {}", hand.bid, index + 1);
+                hand.bid * (index + 1) as u32
+            })
+            .sum()
+    }
+}
+
+///
+/// Parse a game with the standard rules, one hand per line. Blank lines are ignored.
+///
+impl FromStr for Game {
+    type Err = GameParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let hands = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                line.parse::<Hand>().map_err(|error| GameParseError {
+                    line: index + 1,
+                    error,
+                })
+            })
+            .collect::<Result<Vec<Hand>, GameParseError>>()?;
+        Ok(Game::new(hands))
+    }
+}
+
+pub fn sample() -> Example {
+    aoc::example!(7, "sample")
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Parsed = Game;
+
+    fn parse(input: &str) -> aoc::Result<Self::Parsed> {
+        Ok(input.parse::<Game>()?)
+    }
+
+    fn part1(game: &Self::Parsed) -> aoc::Result<Answer> {
+        Ok(game.play().into())
+    }
+
+    /// Total winnings of the same hands, with the jokers.
+    fn part2(game: &Self::Parsed) -> aoc::Result<Answer> {
+        let game_with_jokers = Game::with_rules(game.hands.clone(), Box::new(JokerRules));
+        Ok(game_with_jokers.play().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::test_util::Lcg;
+
+    use super::*;
+
+    #[test]
+    fn test_solution_sample() {
+        let game = Day7::parse(&sample().input).unwrap();
+        assert_eq!(Day7::part1(&game).unwrap(), Answer::Number(6440));
+        assert_eq!(Day7::part2(&game).unwrap(), Answer::Number(5905));
+    }
+
+    #[test]
+    fn test_hand_ordering_less() {
+        let hand1 = Hand::new("32T3K", 0).unwrap();
+        let hand2 = Hand::new("T55J5", 0).unwrap();
+        assert!(hand1 < hand2);
+    }
+
+    #[test]
+    fn test_hand_ordering_second_criteria() {
+        let hand1 = Hand::new("3233K", 0).unwrap();
+        let hand2 = Hand::new("T55J5", 0).unwrap();
+        assert!(hand1 < hand2);
+    }
+
+    #[test]
+    fn test_hand_ordering_second_criteria_greater() {
+        let hand1 = Hand::new("QQQJA", 0).unwrap();
+        let hand2 = Hand::new("T55J5", 0).unwrap();
+        assert!(hand1 > hand2);
+    }
+
+    #[test]
+    fn test_hand_ordering_equal() {
+        let hand1 = Hand::new("T55J5", 0).unwrap();
+        let hand2 = Hand::new("T55J5", 0).unwrap();
+        assert_eq!(hand1.cmp(&hand2), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_hand_ordering_greater() {
+        let hand1 = Hand::new("55555", 0).unwrap();
+        let hand2 = Hand::new("T55J5", 0).unwrap();
+        assert_eq!(hand1.cmp(&hand2), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_hand_debug_leaves_out_standard_type() {
+        let hand = Hand::new("KTJJT", 220).unwrap();
+        assert_eq!(
+            format!("{:?}", hand),
+            "Hand { cards: [King, Ten, Jack, Jack, Ten], bid: 220 }"
+        );
+    }
+    #[test]
+    fn test_hand_type_high_card() {
+        let hand = Hand::new("23456", 684).unwrap();
+        assert_eq!(hand.hand_type(), Type::HighCard);
+    }
+    #[test]
+    fn test_hand_type_one_pair() {
+        let hand = Hand::new("32T3K", 765).unwrap();
+        assert_eq!(hand.hand_type(), Type::OnePair);
+    }
+    #[test]
+    fn test_hand_type_two_pair() {
+        let hand = Hand::new("32T32", 765).unwrap();
+        assert_eq!(hand.hand_type(), Type::TwoPair);
+    }
+    #[test]
+    fn test_hand_type_full_house() {
+        let hand = Hand::new("T55J5", 684).unwrap();
+        assert_eq!(hand.hand_type(), Type::ThreeOfAKind);
+    }
+    #[test]
+    fn test_hand_type_four_of_a_kind() {
+        let hand = Hand::new("K2KKK", 765).unwrap();
+        assert_eq!(hand.hand_type(), Type::FourOfAKind);
+    }
+    #[test]
+    fn test_hand_type_five_of_a_kind() {
+        let hand = Hand::new("KKKKK", 765).unwrap();
+        assert_eq!(hand.hand_type(), Type::FiveOfAKind);
+    }
+
+    #[test]
+    fn test_game_with_jokers_sample() {
+        let hands = sample().input.parse::<Game>().unwrap().hands;
+        let game = Game::with_rules(hands, Box::new(JokerRules));
+        assert_eq!(game.play(), 5905);
+    }
+
+    #[test]
+    fn test_hand_equality_same_type_different_cards() {
+        let hand1 = Hand::new("32T3K", 0).unwrap();
+        let hand2 = Hand::new("KK677", 0).unwrap();
+        assert_ne!(hand1, hand2);
+        assert_ne!(hand1.cmp(&hand2), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_hand_equality_ignores_bid() {
+        let hand1 = Hand::new("T55J5", 1).unwrap();
+        let hand2 = Hand::new("T55J5", 2).unwrap();
+        assert_eq!(hand1, hand2);
+    }
+
+    fn hash_of(hand: &Hand) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        hand.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Random hands from a small alphabet, so that equal hands show up often enough.
+    fn random_hands(count: usize) -> Vec<Hand> {
+        let alphabet = ['2', '3', 'J', 'Q', 'K'];
+        let mut rng = Lcg::new(7);
+        (0..count)
+            .map(|_| {
+                let cards: String = (0..5)
+                    .map(|_| alphabet[rng.next(alphabet.len() as u64) as usize])
+                    .collect();
+                Hand::new(&cards, rng.next(1000) as u32).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn property_equality_agrees_with_ordering_and_hash() {
+        let hands = random_hands(200);
+        let mut equal_pairs = 0;
+        for a in &hands {
+            for b in &hands {
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{:?} {:?}", a, b);
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{:?} {:?}", a, b);
+                if a == b {
+                    assert_eq!(hash_of(a), hash_of(b));
+                    equal_pairs += 1;
+                }
+            }
+        }
+        // make sure the property has been checked on distinct but equal hands too
+        assert!(equal_pairs > hands.len());
+    }
+
+    #[test]
+    fn test_hand_new_wrong_number_of_cards() {
+        assert_eq!(
+            Hand::new("32T3", 0).unwrap_err(),
+            HandParseError::WrongNumberOfCards(4)
+        );
+        // counted in characters, not in bytes
+        assert_eq!(
+            Hand::new("32T3é", 0).unwrap_err(),
+            HandParseError::InvalidCard('é')
+        );
+        assert_eq!(
+            Hand::new("é32T", 0).unwrap_err(),
+            HandParseError::InvalidCard('é')
+        );
+    }
+
+    #[test]
+    fn test_card_try_from_invalid() {
+        assert_eq!(Card::try_from('A'), Ok(Card::Ace));
+        assert_eq!(Card::try_from('x'), Err(HandParseError::InvalidCard('x')));
+    }
+
+    #[test]
+    fn test_hand_from_str() {
+        let hand: Hand = "32T3K 765".parse().unwrap();
+        assert_eq!(hand.bid, 765);
+        assert_eq!(hand.hand_type(), Type::OnePair);
+        assert_eq!("32T3K".parse::<Hand>(), Err(HandParseError::MissingBid));
+        assert_eq!(
+            "32T3K x".parse::<Hand>(),
+            Err(HandParseError::InvalidBid("x".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_game_from_str_sample() {
+        let game: Game = sample().input.parse().unwrap();
+        assert_eq!(game.hands.len(), 5);
+        assert_eq!(game.play(), 6440);
+    }
+
+    #[test]
+    fn test_game_from_str_reports_line() {
+        let error = "32T3K 765\n\nT55X5 684".parse::<Game>().err().unwrap();
+        assert_eq!(
+            error,
+            GameParseError {
+                line: 3,
+                error: HandParseError::InvalidCard('X')
+            }
+        );
+        assert_eq!(error.to_string(), "line 3: invalid card 'X'");
+    }
+
+    #[test]
+    fn test_game_rank_agrees_with_ruleset_compare() {
+        for rules in ["standard", "jokers", "poker"] {
+            let game = Game::with_rules(random_hands(200), ruleset_from_name(rules).unwrap());
+            let ranked = game.rank();
+            for pair in ranked.windows(2) {
+                assert_ne!(
+                    game.rules.compare(&pair[0].cards, &pair[1].cards),
+                    Ordering::Greater
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_game_report_sample() {
+        let game: Game = sample().input.parse().unwrap();
+        let report = game.report();
+        assert_eq!(report.total_winnings(), 6440);
+        assert_eq!(
+            report.standings[4],
+            Standing {
+                rank: 5,
+                cards: "QQQJA".to_string(),
+                hand_type: Type::ThreeOfAKind,
+                bid: 483,
+                winnings: 2415,
+            }
+        );
+    }
+
+    #[test]
+    fn test_game_report_with_jokers_uses_game_rules() {
+        let hands = sample().input.parse::<Game>().unwrap().hands;
+        let report = Game::with_rules(hands, Box::new(JokerRules)).report();
+        assert_eq!(report.total_winnings(), 5905);
+        assert_eq!(report.standings[4].cards, "KTJJT");
+        assert_eq!(report.standings[4].hand_type, Type::FourOfAKind);
+    }
+
+    /// Ranking with the types classified once against classifying them on every comparison.
+    #[test]
+    #[ignore]
+    fn bench_rank_one_million_hands() {
+        for rules in ["standard", "jokers"] {
+            let game = Game::with_rules(random_hands(1_000_000), ruleset_from_name(rules).unwrap());
+
+            let start = std::time::Instant::now();
+            let ranked = game.rank();
+            let cached = start.elapsed();
+
+            let mut sorted_hands: Vec<&Hand> = game.hands.iter().collect();
+            let start = std::time::Instant::now();
+            sorted_hands.sort_by(|a, b| game.rules.compare(&a.cards, &b.cards));
+            let uncached = start.elapsed();
+
+            assert_eq!(ranked.len(), sorted_hands.len());
+            println!(
+                "{} rules, 1M hands: classified once {:?}, classified on every comparison {:?}",
+                rules, cached, uncached
+            );
+        }
+    }
+}
@@ -1,260 +1,57 @@
-use std::cmp::Ordering;
-use std::collections::HashMap;
+use aoc::{Part, Solution};
+use day7::{ruleset_from_name, sample, Day7, Game};
 
-/**
-
-Exercise https://adventofcode.com/2023/day/7
-
-
-**/
-
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
-enum Type {
-    HighCard,
-    OnePair,
-    TwoPair,
-    ThreeOfAKind,
-    FullHouse,
-    FourOfAKind,
-    FiveOfAKind,
-}
-
-#[derive(Debug, Copy, Ord, PartialOrd, Eq, PartialEq, Hash, Clone)]
-enum Card {
-    Two,
-    Three,
-    Four,
-    Five,
-    Six,
-    Seven,
-    Eight,
-    Nine,
-    Ten,
-    Jack,
-    Queen,
-    King,
-    Ace,
-}
-
-impl From<char> for Card {
-    fn from(value: char) -> Self {
-        match value {
-            '2' => Card::Two,
-            '3' => Card::Three,
-            '4' => Card::Four,
-            '5' => Card::Five,
-            '6' => Card::Six,
-            '7' => Card::Seven,
-            '8' => Card::Eight,
-            '9' => Card::Nine,
-            'T' => Card::Ten,
-            'J' => Card::Jack,
-            'Q' => Card::Queen,
-            'K' => Card::King,
-            'A' => Card::Ace,
-            _ => panic!("Invalid card: {}", value),
-        }
-    }
-}
-
-#[derive(Debug)]
-pub struct Hand {
-    cards: [Card; 5],
-    bid: u32,
-}
-
-impl Hand {
-    pub fn new(cards_as_string: &str, bid: u32) -> Result<Self, &str> {
-        //validate input
-        if cards_as_string.len() != 5 {
-            return Err("Hand must consist of 5 cards");
-        }
-        if !cards_as_string
-            .chars()
-            .all(|c| c.is_digit(10) || "TJQKA".contains(c))
-        {
-            return Err("Hand must consist of uppercase letters or digits");
-        }
-        let cards = cards_as_string
-            .chars()
-            .map(|c| Card::from(c))
-            .collect::<Vec<Card>>()
-            .try_into()
-            .unwrap();
-        Ok(Hand { cards, bid })
-    }
-
-    fn type_of_hand(&self) -> Type {
-        let map_with_count = self.cards.iter().fold(HashMap::new(), |mut acc, card| {
-            acc.entry(*card).and_modify(|v| *v += 1).or_insert(1);
-            acc
-        });
-
-        if map_with_count.iter().map(|kv| *kv.1).max().unwrap() == 5 {
-            return Type::FiveOfAKind;
-        }
-        if map_with_count.iter().map(|kv| *kv.1).max().unwrap() == 4 {
-            return Type::FourOfAKind;
-        }
-        if map_with_count.iter().map(|kv| *kv.1).max().unwrap() == 3
-            && map_with_count.iter().map(|kv| *kv.1).min().unwrap() == 2
-        {
-            return Type::FullHouse;
-        }
-        if map_with_count.iter().map(|kv| *kv.1).max().unwrap() == 3 {
-            return Type::ThreeOfAKind;
-        }
-        if map_with_count.iter().filter(|a| *a.1 == 2).count() == 2 {
-            return Type::TwoPair;
-        }
-        if map_with_count.iter().filter(|a| *a.1 == 2).count() == 1 {
-            return Type::OnePair;
-        }
-
-        Type::HighCard
-    }
-}
-
-impl Ord for Hand {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.type_of_hand()
-            .cmp(&other.type_of_hand())
-            .then_with(|| {
-                for (index, card) in self.cards.iter().enumerate() {
-                    match card.cmp(&other.cards[index]) {
-                        Ordering::Greater => return Ordering::Greater,
-                        Ordering::Less => return Ordering::Less,
-                        Ordering::Equal => continue,
-                    }
-                }
-                Ordering::Equal
-            })
-    }
-}
-
-impl Eq for Hand {}
-
-impl PartialEq<Self> for Hand {
-    fn eq(&self, other: &Self) -> bool {
-        self.type_of_hand() == other.type_of_hand()
-    }
-}
-
-impl PartialOrd<Self> for Hand {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-pub struct Game {
-    pub hands: Vec<Hand>,
-}
-
-impl Game {
-    pub fn new(hands: Vec<Hand>) -> Self {
-        Game { hands }
-    }
-
-    pub fn play(&self) -> u32 {
-        let mut sorted_hands: Vec<&Hand> = self.hands.iter().clone().collect();
-        sorted_hands.sort();
-        println!("Sorted hands: {:?}", sorted_hands);
-        sorted_hands
-            .iter()
-            .enumerate()
-            .map(|h| {
-                println!("hand with bid {} multiply rank {}", h.1.bid, h.0 + 1);
-                h.1.bid * (h.0 + 1) as u32
-            })
-            .sum()
-    }
-}
+const USAGE: &str = "usage: day7 [--rules <standard|jokers|poker>] [--report <table|csv|json>]";
 
 fn main() {
-    println!("AOC 2023 day 7.");
+    aoc::log::init();
 
-    let hand1 = Hand::new("32T3K", 765).unwrap();
-    let hand2 = Hand::new("T55J5", 684).unwrap();
-    let hand3 = Hand::new("KK677", 28).unwrap();
-    let hand4 = Hand::new("KTJJT", 220).unwrap();
-    let hand5 = Hand::new("QQQJA", 483).unwrap();
+    println!("AOC 2023 day 7.");
 
-    let game = Game::new(vec![hand1, hand2, hand3, hand4, hand5]);
+    let sample = sample();
+    let game: Game = Day7::parse(&sample.input).unwrap();
 
-    println!("Game hands: {:?}", game.hands);
+    aoc::debug!("Game hands: {:?}", game.hands);
 
-    let total_bid = game.play();
+    let total_bid = Day7::part1(&game).unwrap();
     println!("Total bid: {}", total_bid);
-    assert_eq!(total_bid, 6440);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_hand_ordering_less() {
-        let hand1 = Hand::new("32T3K", 0);
-        let hand2 = Hand::new("T55J5", 0);
-        assert_eq!(hand1 > hand2, false);
-    }
-
-    #[test]
-    fn test_hand_ordering_second_criteria() {
-        let hand1 = Hand::new("3233K", 0);
-        let hand2 = Hand::new("T55J5", 0);
-        assert_eq!(hand1 > hand2, false);
-    }
-
-    #[test]
-    fn test_hand_ordering_second_criteria_greater() {
-        let hand1 = Hand::new("QQQJA", 0);
-        let hand2 = Hand::new("T55J5", 0);
-        assert_eq!(hand1 > hand2, true);
-    }
-
-    #[test]
-    fn test_hand_ordering_equal() {
-        let hand1 = Hand::new("T55J5", 0);
-        let hand2 = Hand::new("T55J5", 0);
-        assert_eq!(hand1.cmp(&hand2), Ordering::Equal);
-    }
-
-    #[test]
-    fn test_hand_ordering_greater() {
-        let hand1 = Hand::new("55555", 0);
-        let hand2 = Hand::new("T55J5", 0);
-        assert_eq!(hand1.cmp(&hand2), Ordering::Greater);
-    }
-
-    #[test]
-    fn test_hand_type_high_card() {
-        let hand = Hand::new("23456", 684).unwrap();
-        assert_eq!(hand.type_of_hand(), Type::HighCard);
-    }
-    #[test]
-    fn test_hand_type_one_pair() {
-        let hand = Hand::new("32T3K", 765).unwrap();
-        assert_eq!(hand.type_of_hand(), Type::OnePair);
-    }
-    #[test]
-    fn test_hand_type_two_pair() {
-        let hand = Hand::new("32T32", 765).unwrap();
-        assert_eq!(hand.type_of_hand(), Type::TwoPair);
-    }
-    #[test]
-    fn test_hand_type_full_house() {
-        let hand = Hand::new("T55J5", 684).unwrap();
-        assert_eq!(hand.type_of_hand(), Type::ThreeOfAKind);
-    }
-    #[test]
-    fn test_hand_type_four_of_a_kind() {
-        let hand = Hand::new("K2KKK", 765).unwrap();
-        assert_eq!(hand.type_of_hand(), Type::FourOfAKind);
-    }
-    #[test]
-    fn test_hand_type_five_of_a_kind() {
-        let hand = Hand::new("KKKKK", 765).unwrap();
-        assert_eq!(hand.type_of_hand(), Type::FiveOfAKind);
+    assert_eq!(Some(total_bid), sample.answer(Part::One));
+
+    let total_bid_with_jokers = Day7::part2(&game).unwrap();
+    println!("Total bid with jokers: {}", total_bid_with_jokers);
+    assert_eq!(Some(total_bid_with_jokers), sample.answer(Part::Two));
+
+    // e.g. `cargo run -- --rules poker --report table`
+    let args: Vec<String> = std::env::args().collect();
+    let option = |name: &str| {
+        args.iter()
+            .position(|arg| arg == name)
+            .and_then(|index| args.get(index + 1))
+    };
+    let rules_name = option("--rules").map_or("standard", String::as_str);
+    let Some(rules) = ruleset_from_name(rules_name) else {
+        eprintln!("{}", USAGE);
+        std::process::exit(1);
+    };
+    let game_with_rules = Game::with_rules(game.hands, rules);
+    if option("--rules").is_some() {
+        let total_bid_with_rules = game_with_rules.play();
+        println!(
+            "Total bid with {} rules: {}",
+            rules_name, total_bid_with_rules
+        );
+    }
+    if let Some(format) = option("--report") {
+        let report = game_with_rules.report();
+        let rendered_report = match format.as_str() {
+            "table" => report.to_table(),
+            "csv" => report.to_csv(),
+            "json" => report.to_json(),
+            _ => {
+                eprintln!("{}", USAGE);
+                std::process::exit(1);
+            }
+        };
+        println!("{}", rendered_report);
     }
 }
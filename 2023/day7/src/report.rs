@@ -0,0 +1,175 @@
+use crate::Type;
+
+/// Final position of a hand in a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    /// 1 for the weakest hand.
+    pub rank: usize,
+    pub cards: String,
+    /// Type of the hand under the rules of the game.
+    pub hand_type: Type,
+    pub bid: u32,
+    pub winnings: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub standings: Vec<Standing>,
+}
+
+const HEADERS: [&str; 5] = ["rank", "cards", "type", "bid", "winnings"];
+
+impl Report {
+    pub fn total_winnings(&self) -> u32 {
+        self.standings
+            .iter()
+            .map(|standing| standing.winnings)
+            .sum()
+    }
+
+    fn rows(&self) -> Vec<[String; 5]> {
+        self.standings
+            .iter()
+            .map(|standing| {
+                [
+                    standing.rank.to_string(),
+                    standing.cards.clone(),
+                    format!("{:?}", standing.hand_type),
+                    standing.bid.to_string(),
+                    standing.winnings.to_string(),
+                ]
+            })
+            .collect()
+    }
+
+    ///
+    /// Render the standings as a table with aligned columns and the total winnings at the bottom, e.g.
+    ///
+    /// ```text
+    /// rank  cards  type     bid  winnings
+    ///    1  32T3K  OnePair  765       765
+    /// ```
+    ///
+    pub fn to_table(&self) -> String {
+        let rows = self.rows();
+        let mut widths = HEADERS.map(str::len);
+        for row in &rows {
+            for (width, value) in widths.iter_mut().zip(row) {
+                *width = (*width).max(value.len());
+            }
+        }
+
+        // numbers are right-aligned, text is left-aligned
+        let format_row = |row: [&str; 5]| -> String {
+            let line = row
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(column, (value, width))| match column {
+                    1 | 2 => format!("{:<width$}", value, width = width),
+                    _ => format!("{:>width$}", value, width = width),
+                })
+                .collect::<Vec<String>>()
+                .join("  ");
+            line.trim_end().to_string()
+        };
+
+        let mut lines = vec![format_row(HEADERS)];
+        for row in &rows {
+            lines.push(format_row([
+                row[0].as_str(),
+                row[1].as_str(),
+                row[2].as_str(),
+                row[3].as_str(),
+                row[4].as_str(),
+            ]));
+        }
+        lines.push(format!("total winnings: {}", self.total_winnings()));
+        lines.join("\n")
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut lines = vec![HEADERS.join(",")];
+        for row in self.rows() {
+            lines.push(row.join(","));
+        }
+        lines.join("\n")
+    }
+
+    pub fn to_json(&self) -> String {
+        let standings: Vec<String> = self
+            .standings
+            .iter()
+            .map(|standing| {
+                format!(
+                    r#"    {{"rank": {}, "cards": "{}", "type": "{:?}", "bid": {}, "winnings": {}}}"#,
+                    standing.rank, standing.cards, standing.hand_type, standing.bid, standing.winnings
+                )
+            })
+            .collect();
+        format!(
+            "{{\n  \"total_winnings\": {},\n  \"standings\": [\n{}\n  ]\n}}",
+            self.total_winnings(),
+            standings.join(",\n")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        Report {
+            standings: vec![
+                Standing {
+                    rank: 1,
+                    cards: "32T3K".to_string(),
+                    hand_type: Type::OnePair,
+                    bid: 765,
+                    winnings: 765,
+                },
+                Standing {
+                    rank: 2,
+                    cards: "KTJJT".to_string(),
+                    hand_type: Type::TwoPair,
+                    bid: 220,
+                    winnings: 440,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_report_to_table() {
+        assert_eq!(
+            report().to_table(),
+            "rank  cards  type     bid  winnings\n\
+             \x20  1  32T3K  OnePair  765       765\n\
+             \x20  2  KTJJT  TwoPair  220       440\n\
+             total winnings: 1205"
+        );
+    }
+
+    #[test]
+    fn test_report_to_csv() {
+        assert_eq!(
+            report().to_csv(),
+            "rank,cards,type,bid,winnings\n1,32T3K,OnePair,765,765\n2,KTJJT,TwoPair,220,440"
+        );
+    }
+
+    #[test]
+    fn test_report_to_json() {
+        assert_eq!(
+            report().to_json(),
+            r#"{
+  "total_winnings": 1205,
+  "standings": [
+    {"rank": 1, "cards": "32T3K", "type": "OnePair", "bid": 765, "winnings": 765},
+    {"rank": 2, "cards": "KTJJT", "type": "TwoPair", "bid": 220, "winnings": 440}
+  ]
+}"#
+        );
+    }
+}
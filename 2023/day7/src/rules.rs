@@ -0,0 +1,261 @@
+use std::cmp::Ordering;
+
+use crate::{Card, Type};
+
+///
+/// The rules used to rank hands in a `Game`.
+///
+/// Only `card_strength` is required: the default classifier groups equal cards, with the
+/// `wildcards` joining the largest group, and the default tie-break compares the strength
+/// of each card in order.
+///
+/// Hands are always ranked by type first and then by `compare_cards`, so that callers sorting
+/// many hands can classify each of them only once.
+///
+pub trait Ruleset {
+    /// Strength of a single card, higher is stronger.
+    fn card_strength(&self, card: Card) -> u8;
+
+    /// Cards that act like whatever card makes the strongest hand type.
+    fn wildcards(&self) -> &[Card] {
+        &[]
+    }
+
+    fn classify(&self, cards: &[Card; 5]) -> Type {
+        classify_by_groups(cards, self.wildcards())
+    }
+
+    /// Break the tie between two hands of the same type.
+    fn compare_cards(&self, cards: &[Card; 5], other: &[Card; 5]) -> Ordering {
+        for (index, card) in cards.iter().enumerate() {
+            match self
+                .card_strength(*card)
+                .cmp(&self.card_strength(other[index]))
+            {
+                Ordering::Greater => return Ordering::Greater,
+                Ordering::Less => return Ordering::Less,
+                Ordering::Equal => continue,
+            }
+        }
+        Ordering::Equal
+    }
+
+    fn compare(&self, cards: &[Card; 5], other: &[Card; 5]) -> Ordering {
+        self.classify(cards)
+            .cmp(&self.classify(other))
+            .then_with(|| self.compare_cards(cards, other))
+    }
+}
+
+/// Camel cards: `J` is a Jack, ranking between Ten and Queen.
+pub struct StandardRules;
+
+impl Ruleset for StandardRules {
+    fn card_strength(&self, card: Card) -> u8 {
+        card as u8
+    }
+}
+
+/// Camel cards with jokers: `J` is a wildcard, but the weakest card when comparing hands of the same type.
+pub struct JokerRules;
+
+impl Ruleset for JokerRules {
+    fn card_strength(&self, card: Card) -> u8 {
+        match card {
+            Card::Jack => 0,
+            card => card as u8 + 1,
+        }
+    }
+
+    fn wildcards(&self) -> &[Card] {
+        &[Card::Jack]
+    }
+}
+
+///
+/// Poker without suits: five consecutive cards make a straight (the Ace can also be low, as in `A2345`),
+/// and hands of the same type are compared by their groups of cards, biggest group first, instead of by position.
+///
+pub struct PokerRules;
+
+impl PokerRules {
+    /// Card strengths ordered by group size and then by strength, e.g. `K2KK2` gives `[K, K, K, 2, 2]`.
+    fn ranking_key(&self, cards: &[Card; 5]) -> Vec<u8> {
+        if let Some(highest) = self.straight_high_card(cards) {
+            return vec![highest];
+        }
+        let counts = count_cards(cards);
+        let mut sorted_cards = *cards;
+        sorted_cards.sort_by(|a, b| counts[*b as usize].cmp(&counts[*a as usize]).then(b.cmp(a)));
+        sorted_cards
+            .iter()
+            .map(|card| self.card_strength(*card))
+            .collect()
+    }
+
+    /// Strength of the highest card of a straight, if the cards make one.
+    fn straight_high_card(&self, cards: &[Card; 5]) -> Option<u8> {
+        let mut strengths: Vec<u8> = cards.iter().map(|card| self.card_strength(*card)).collect();
+        strengths.sort();
+        strengths.dedup();
+        if strengths.len() != 5 {
+            return None;
+        }
+        if strengths[4] - strengths[0] == 4 {
+            return Some(strengths[4]);
+        }
+        // the wheel: the Ace plays as a one, below the Two
+        if strengths == [Card::Two, Card::Three, Card::Four, Card::Five, Card::Ace].map(|c| c as u8)
+        {
+            return Some(Card::Five as u8);
+        }
+        None
+    }
+}
+
+impl Ruleset for PokerRules {
+    fn card_strength(&self, card: Card) -> u8 {
+        card as u8
+    }
+
+    fn classify(&self, cards: &[Card; 5]) -> Type {
+        if self.straight_high_card(cards).is_some() {
+            return Type::Straight;
+        }
+        classify_by_groups(cards, &[])
+    }
+
+    fn compare_cards(&self, cards: &[Card; 5], other: &[Card; 5]) -> Ordering {
+        self.ranking_key(cards).cmp(&self.ranking_key(other))
+    }
+}
+
+/// Look up a ruleset by the name used on the command line.
+pub fn ruleset_from_name(name: &str) -> Option<Box<dyn Ruleset>> {
+    match name {
+        "standard" => Some(Box::new(StandardRules)),
+        "jokers" => Some(Box::new(JokerRules)),
+        "poker" => Some(Box::new(PokerRules)),
+        _ => None,
+    }
+}
+
+/// Number of cards of each kind, indexed by `Card as usize`.
+fn count_cards(cards: &[Card; 5]) -> [u8; 13] {
+    let mut counts = [0; 13];
+    for card in cards {
+        counts[*card as usize] += 1;
+    }
+    counts
+}
+
+/// Classify the hand by its groups of equal cards, adding the wildcards to the largest group.
+fn classify_by_groups(cards: &[Card; 5], wildcards: &[Card]) -> Type {
+    let mut counts = count_cards(cards);
+
+    let mut jokers = 0;
+    for wildcard in wildcards {
+        jokers += counts[*wildcard as usize];
+        counts[*wildcard as usize] = 0;
+    }
+
+    // the two biggest groups are enough to tell every type apart
+    let (mut first, mut second) = (0, 0);
+    for count in counts {
+        if count > first {
+            second = first;
+            first = count;
+        } else if count > second {
+            second = count;
+        }
+    }
+    // the jokers always join the most frequent card, which gives the best type
+    first += jokers;
+
+    match (first, second) {
+        (5, _) => Type::FiveOfAKind,
+        (4, _) => Type::FourOfAKind,
+        (3, 2) => Type::FullHouse,
+        (3, _) => Type::ThreeOfAKind,
+        (2, 2) => Type::TwoPair,
+        (2, _) => Type::OnePair,
+        _ => Type::HighCard,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(cards_as_string: &str) -> [Card; 5] {
+        cards_as_string
+            .chars()
+            .map(|c| Card::try_from(c).unwrap())
+            .collect::<Vec<Card>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_joker_rules_upgrade_type() {
+        assert_eq!(JokerRules.classify(&cards("T55J5")), Type::FourOfAKind);
+        assert_eq!(JokerRules.classify(&cards("KKJTT")), Type::FullHouse);
+        assert_eq!(JokerRules.classify(&cards("JJJJJ")), Type::FiveOfAKind);
+        assert_eq!(StandardRules.classify(&cards("KKJTT")), Type::TwoPair);
+    }
+
+    #[test]
+    fn test_joker_rules_joker_is_weakest() {
+        assert_eq!(
+            JokerRules.compare(&cards("JKKK2"), &cards("QQQQ2")),
+            Ordering::Less
+        );
+        assert_eq!(
+            StandardRules.compare(&cards("JKKK2"), &cards("QQQQ2")),
+            Ordering::Less
+        );
+        assert_eq!(
+            StandardRules.compare(&cards("JKKK2"), &cards("TKKK2")),
+            Ordering::Greater
+        );
+        assert_eq!(
+            JokerRules.compare(&cards("JKKK2"), &cards("2KKKK")),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_poker_rules_straight() {
+        assert_eq!(PokerRules.classify(&cards("T9J8Q")), Type::Straight);
+        assert_eq!(PokerRules.classify(&cards("A2345")), Type::Straight);
+        assert_eq!(PokerRules.classify(&cards("KA234")), Type::HighCard);
+        assert_eq!(StandardRules.classify(&cards("T9J8Q")), Type::HighCard);
+    }
+
+    #[test]
+    fn test_poker_rules_ordering() {
+        // a straight beats three of a kind, but not a full house
+        assert_eq!(
+            PokerRules.compare(&cards("23456"), &cards("AAAK2")),
+            Ordering::Greater
+        );
+        assert_eq!(
+            PokerRules.compare(&cards("23456"), &cards("22333")),
+            Ordering::Less
+        );
+        // the wheel is the weakest straight
+        assert_eq!(
+            PokerRules.compare(&cards("A2345"), &cards("23456")),
+            Ordering::Less
+        );
+        // pairs are compared before kickers, whatever their position
+        assert_eq!(
+            PokerRules.compare(&cards("A2K2Q"), &cards("3Q3KA")),
+            Ordering::Less
+        );
+        assert_eq!(
+            PokerRules.compare(&cards("K2KK2"), &cards("QQQAA")),
+            Ordering::Greater
+        );
+    }
+}
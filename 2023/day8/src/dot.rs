@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use crate::{DesertMap, Direction, NodeId};
+
+const START_COLOR: &str = "palegreen";
+const END_COLOR: &str = "lightcoral";
+const PATH_COLOR: &str = "blue";
+
+impl DesertMap {
+    ///
+    /// Render the map in the DOT language of Graphviz, with an edge per direction labelled `L` or `R`
+    /// (a single `L,R` edge when both directions lead to the same node), and the start and end nodes filled.
+    ///
+    /// With `show_path`, the edges walked from `start_node` to `end_node` are drawn in bold.
+    /// Nothing is drawn when the end node is unreachable.
+    ///
+    pub fn to_dot(&self, start_node: NodeId, end_node: NodeId, show_path: bool) -> String {
+        let walked_edges: HashSet<(NodeId, Direction)> = if show_path {
+            self.path_to_destination(start_node, end_node)
+                .map(|path| path.into_iter().collect())
+                .unwrap_or_default()
+        } else {
+            HashSet::new()
+        };
+
+        let mut lines = vec!["digraph desert_map {".to_string()];
+        lines.push(format!(
+            "    {} [style=filled, fillcolor={}];",
+            quote(self.label(start_node)),
+            START_COLOR
+        ));
+        if end_node != start_node {
+            lines.push(format!(
+                "    {} [style=filled, fillcolor={}];",
+                quote(self.label(end_node)),
+                END_COLOR
+            ));
+        }
+
+        for index in 0..self.labels.len() {
+            let node = NodeId(index);
+            let destination = self.map[index];
+            let edges: Vec<(NodeId, &str, Vec<Direction>)> =
+                if destination.left == destination.right {
+                    vec![(destination.left, "L,R", vec![Direction::L, Direction::R])]
+                } else {
+                    vec![
+                        (destination.left, "L", vec![Direction::L]),
+                        (destination.right, "R", vec![Direction::R]),
+                    ]
+                };
+            for (next_node, label, directions) in edges {
+                let walked = directions
+                    .iter()
+                    .any(|direction| walked_edges.contains(&(node, *direction)));
+                let style = if walked {
+                    format!(", color={}, penwidth=2", PATH_COLOR)
+                } else {
+                    String::new()
+                };
+                lines.push(format!(
+                    "    {} -> {} [label=\"{}\"{}];",
+                    quote(self.label(node)),
+                    quote(self.label(next_node)),
+                    label,
+                    style
+                ));
+            }
+        }
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+fn quote(label: &str) -> String {
+    format!("\"{}\"", label.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_sample_when_to_dot_then_graph_with_highlights() {
+        let map: DesertMap = crate::sample().input.parse().unwrap();
+        let start_node = map.node_id("AAA").unwrap();
+        let end_node = map.node_id("ZZZ").unwrap();
+
+        let dot = map.to_dot(start_node, end_node, false);
+
+        assert_eq!(
+            dot,
+            r#"digraph desert_map {
+    "AAA" [style=filled, fillcolor=palegreen];
+    "ZZZ" [style=filled, fillcolor=lightcoral];
+    "AAA" -> "BBB" [label="L,R"];
+    "BBB" -> "AAA" [label="L"];
+    "BBB" -> "ZZZ" [label="R"];
+    "ZZZ" -> "ZZZ" [label="L,R"];
+}"#
+        );
+    }
+
+    #[test]
+    fn given_sample_when_to_dot_with_path_then_walked_edges_are_bold() {
+        let map: DesertMap = crate::sample().input.parse().unwrap();
+        let start_node = map.node_id("AAA").unwrap();
+        let end_node = map.node_id("ZZZ").unwrap();
+
+        let dot = map.to_dot(start_node, end_node, true);
+
+        assert!(dot.contains(r#""AAA" -> "BBB" [label="L,R", color=blue, penwidth=2];"#));
+        assert!(dot.contains(r#""BBB" -> "AAA" [label="L", color=blue, penwidth=2];"#));
+        assert!(dot.contains(r#""BBB" -> "ZZZ" [label="R", color=blue, penwidth=2];"#));
+        assert!(dot.contains(r#""ZZZ" -> "ZZZ" [label="L,R"];"#));
+    }
+
+    #[test]
+    fn given_unreachable_end_when_to_dot_with_path_then_no_bold_edge() {
+        let input = "L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        let map: DesertMap = input.parse().unwrap();
+
+        let dot = map.to_dot(
+            map.node_id("AAA").unwrap(),
+            map.node_id("ZZZ").unwrap(),
+            true,
+        );
+
+        assert!(!dot.contains("penwidth"));
+    }
+
+    #[test]
+    fn test_quote_escapes_label() {
+        assert_eq!(quote(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+
+use crate::{DesertMap, NodeId};
+
+///
+/// Steps at which a single ghost stands on an end node.
+///
+/// A ghost is fully described by its state, the current node and the index of the next instruction,
+/// so after at most `nodes * instructions` steps it gets back to a state it has already been in,
+/// and from there it loops forever.
+///
+#[derive(Debug, PartialEq, Eq)]
+struct GhostCycle {
+    /// End nodes reached before the loop starts, each of them only once.
+    tail_hits: Vec<u64>,
+    cycle_start: u64,
+    cycle_length: u64,
+    /// End nodes reached during the first lap of the loop, then again every `cycle_length` steps.
+    cycle_hits: Vec<u64>,
+}
+
+impl GhostCycle {
+    fn detect(map: &DesertMap, start: NodeId, is_end: &impl Fn(NodeId) -> bool) -> Self {
+        let mut first_seen: HashMap<(NodeId, usize), u64> = HashMap::new();
+        let mut hits: Vec<u64> = vec![];
+        let mut node = start;
+        let mut step: u64 = 0;
+        loop {
+            let instruction_index = (step % map.instructions.len() as u64) as usize;
+            if let Some(cycle_start) = first_seen.insert((node, instruction_index), step) {
+                let (tail_hits, cycle_hits) = hits.into_iter().partition(|hit| *hit < cycle_start);
+                return GhostCycle {
+                    tail_hits,
+                    cycle_start,
+                    cycle_length: step - cycle_start,
+                    cycle_hits,
+                };
+            }
+            if is_end(node) {
+                hits.push(step);
+            }
+            node = map.next_node(node, map.instructions[instruction_index]);
+            step += 1;
+        }
+    }
+
+    fn is_hit(&self, step: u64) -> bool {
+        self.tail_hits.contains(&step)
+            || self
+                .cycle_hits
+                .iter()
+                .any(|hit| step >= *hit && (step - hit).is_multiple_of(self.cycle_length))
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u128, b: u128) -> u128 {
+    a / gcd(a, b) * b
+}
+
+/// Modular inverse of `a` modulo `m`, with `a` and `m` coprime.
+fn mod_inverse(a: u128, m: u128) -> u128 {
+    // extended euclid on signed values, the coefficients can go below zero
+    let (mut old_r, mut r) = (a as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    old_s.rem_euclid(m as i128) as u128
+}
+
+///
+/// Generalized chinese remainder theorem: combine `x ≡ a (mod m)` and `x ≡ b (mod n)`, with `m` and `n`
+/// not necessarily coprime, into a single `x ≡ c (mod lcm(m, n))`. Returns `None` when there is no solution.
+///
+fn crt(a: u128, m: u128, b: u128, n: u128) -> Option<(u128, u128)> {
+    let g = gcd(m, n);
+    let difference = (b + n - a % n) % n;
+    if !difference.is_multiple_of(g) {
+        return None;
+    }
+    let modulus = lcm(m, n);
+    let k = difference / g * mod_inverse(m / g % (n / g), n / g) % (n / g);
+    Some(((a + m * k) % modulus, modulus))
+}
+
+impl DesertMap {
+    ///
+    /// Count the steps needed for ghosts starting from every node whose label ends with `A`
+    /// to stand all on nodes whose label ends with `Z` at the same time.
+    ///
+    pub fn calculate_ghost_steps(&self) -> Option<u64> {
+        let starts: Vec<NodeId> = (0..self.labels.len())
+            .map(NodeId)
+            .filter(|node| self.label(*node).ends_with('A'))
+            .collect();
+        self.calculate_simultaneous_steps(&starts, |node| self.label(node).ends_with('Z'))
+    }
+
+    ///
+    /// Find the first step where ghosts walking from all `starts` at once are all on an end node,
+    /// without simulating them.
+    ///
+    /// Each ghost is reduced to its loop. Steps before the last loop start are checked one by one,
+    /// after that every ghost only stands on an end node at `hit + k * cycle_length`: when all the hits
+    /// are at multiples of their cycle length the answer is the LCM of the lengths, otherwise the
+    /// congruences are combined with the chinese remainder theorem.
+    ///
+    /// Returns `None` when the ghosts are never all on an end node at the same time, or only after
+    /// more steps than fit in a `u64`.
+    ///
+    pub fn calculate_simultaneous_steps(
+        &self,
+        starts: &[NodeId],
+        is_end: impl Fn(NodeId) -> bool,
+    ) -> Option<u64> {
+        let ghosts: Vec<GhostCycle> = starts
+            .iter()
+            .map(|start| GhostCycle::detect(self, *start, &is_end))
+            .collect();
+        let first_ghost = ghosts.first()?;
+        let all_hit = |step: u64| ghosts.iter().all(|ghost| ghost.is_hit(step));
+
+        // before every ghost is in its loop, candidates are the hits of the first ghost
+        let last_cycle_start = ghosts.iter().map(|ghost| ghost.cycle_start).max().unwrap();
+        let early_candidates =
+            first_ghost
+                .tail_hits
+                .iter()
+                .copied()
+                .chain(first_ghost.cycle_hits.iter().flat_map(|hit| {
+                    (*hit..last_cycle_start).step_by(first_ghost.cycle_length as usize)
+                }));
+        if let Some(step) = early_candidates.filter(|step| all_hit(*step)).min() {
+            return Some(step);
+        }
+
+        let only_multiples = ghosts.iter().all(|ghost| {
+            ghost.cycle_hits.len() == 1 && ghost.cycle_hits[0].is_multiple_of(ghost.cycle_length)
+        });
+        if only_multiples {
+            let period = ghosts
+                .iter()
+                .fold(1, |acc, ghost| lcm(acc, ghost.cycle_length as u128));
+            // first common multiple where every ghost is already in its loop
+            let steps = (last_cycle_start as u128).div_ceil(period) * period;
+            return u64::try_from(steps).ok();
+        }
+
+        // every combination of one hit per ghost gives a system of congruences
+        let mut congruences: Vec<(u128, u128)> = vec![(0, 1)];
+        for ghost in &ghosts {
+            let length = ghost.cycle_length as u128;
+            congruences = congruences
+                .iter()
+                .flat_map(|(residue, modulus)| {
+                    ghost.cycle_hits.iter().filter_map(move |hit| {
+                        crt(*residue, *modulus, *hit as u128 % length, length)
+                    })
+                })
+                .collect();
+        }
+
+        congruences
+            .into_iter()
+            .map(|(residue, modulus)| {
+                // smallest solution where every ghost is already in its loop
+                let start = last_cycle_start as u128;
+                if residue >= start {
+                    residue
+                } else {
+                    residue + (start - residue).div_ceil(modulus) * modulus
+                }
+            })
+            .min()
+            .and_then(|steps| u64::try_from(steps).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::test_util::Lcg;
+
+    use super::*;
+
+    /// Move all the ghosts one step at a time, only usable when the answer is small.
+    fn simulate(map: &DesertMap, starts: &[&str], ends: &[&str], max_steps: u64) -> Option<u64> {
+        let mut nodes: Vec<NodeId> = starts.iter().map(|s| map.node_id(s).unwrap()).collect();
+        let ends: Vec<NodeId> = ends.iter().map(|e| map.node_id(e).unwrap()).collect();
+        for step in 0..max_steps {
+            if nodes.iter().all(|node| ends.contains(node)) {
+                return Some(step);
+            }
+            let direction = map.instructions[step as usize % map.instructions.len()];
+            nodes = nodes
+                .iter()
+                .map(|node| map.next_node(*node, direction))
+                .collect();
+        }
+        None
+    }
+
+    #[test]
+    fn given_sample_when_calculate_ghost_steps_then_6() {
+        let map: DesertMap = crate::ghosts_sample().input.parse().unwrap();
+
+        assert_eq!(map.calculate_ghost_steps(), Some(6));
+    }
+
+    #[test]
+    fn given_loop_with_offset_when_detect_then_tail_and_cycle() {
+        // 1A -> 1B -> 1Z -> 1C -> 1B -> ...
+        let input = "L\n\n1A = (1B, 1B)\n1B = (1Z, 1Z)\n1Z = (1C, 1C)\n1C = (1B, 1B)";
+        let map: DesertMap = input.parse().unwrap();
+
+        let cycle = GhostCycle::detect(&map, map.node_id("1A").unwrap(), &|node| {
+            map.label(node).ends_with('Z')
+        });
+
+        assert_eq!(
+            cycle,
+            GhostCycle {
+                tail_hits: vec![],
+                cycle_start: 1,
+                cycle_length: 3,
+                cycle_hits: vec![2],
+            }
+        );
+    }
+
+    const OFFSET_LOOPS: &str = "L\n\n\
+        1A = (1B, 1B)\n1B = (1Z, 1Z)\n1Z = (1C, 1C)\n1C = (1B, 1B)\n\
+        2A = (2Z, 2Z)\n2Z = (2B, 2B)\n2B = (2C, 2C)\n2C = (2D, 2D)\n2D = (2Z, 2Z)\n\
+        3A = (3Z, 3Z)\n3Z = (3B, 3B)\n3B = (3B, 3B)";
+
+    #[test]
+    fn given_loops_with_non_zero_offsets_when_calculate_then_crt() {
+        let map: DesertMap = OFFSET_LOOPS.parse().unwrap();
+        let starts = [map.node_id("1A").unwrap(), map.node_id("2A").unwrap()];
+
+        // 1A is on an end node at 2, 5, 8, ... and 2A at 1, 5, 9, ...
+        let steps =
+            map.calculate_simultaneous_steps(&starts, |node| map.label(node).ends_with('Z'));
+
+        assert_eq!(steps, Some(5));
+        assert_eq!(steps, simulate(&map, &["1A", "2A"], &["1Z", "2Z"], 1000));
+    }
+
+    #[test]
+    fn given_end_only_before_loop_when_calculate_then_tail_hit() {
+        let map: DesertMap = OFFSET_LOOPS.parse().unwrap();
+        let is_end = |node| map.label(node).ends_with('Z');
+
+        let with_2a = [map.node_id("3A").unwrap(), map.node_id("2A").unwrap()];
+        let with_1a = [map.node_id("3A").unwrap(), map.node_id("1A").unwrap()];
+
+        assert_eq!(map.calculate_simultaneous_steps(&with_2a, is_end), Some(1));
+        assert_eq!(map.calculate_simultaneous_steps(&with_1a, is_end), None);
+    }
+
+    #[test]
+    fn given_incompatible_loops_when_calculate_then_none() {
+        // 1A is on an end node at odd steps only, 2A at even steps only
+        let input =
+            "L\n\n1A = (1Z, 1Z)\n1Z = (1A, 1A)\n2A = (2B, 2B)\n2B = (2Z, 2Z)\n2Z = (2B, 2B)";
+        let map: DesertMap = input.parse().unwrap();
+
+        assert_eq!(map.calculate_ghost_steps(), None);
+    }
+
+    #[test]
+    fn given_coprime_loops_when_calculate_then_lcm() {
+        let input = "L\n\n\
+            1A = (1B, 1B)\n1B = (1Z, 1Z)\n1Z = (1B, 1B)\n\
+            2A = (2B, 2B)\n2B = (2C, 2C)\n2C = (2Z, 2Z)\n2Z = (2B, 2B)";
+        let map: DesertMap = input.parse().unwrap();
+
+        assert_eq!(map.calculate_ghost_steps(), Some(6));
+        assert_eq!(
+            map.calculate_ghost_steps(),
+            simulate(&map, &["1A", "2A"], &["1Z", "2Z"], 1000)
+        );
+    }
+
+    #[test]
+    fn given_loops_whose_lcm_overflows_u64_when_calculate_then_none() {
+        // one loop per prime up to 53, whose product is above u64::MAX
+        let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+        let mut input = "L\n".to_string();
+        for (ghost, prime) in primes.iter().enumerate() {
+            let node = |k: usize| match k {
+                0 => format!("G{}A", ghost),
+                k if k == *prime => format!("G{}Z", ghost),
+                k => format!("G{}N{}", ghost, k),
+            };
+            for k in 0..*prime {
+                input += &format!("\n{} = ({}, {})", node(k), node(k + 1), node(k + 1));
+            }
+            input += &format!("\n{} = ({}, {})", node(*prime), node(1), node(1));
+        }
+        let map: DesertMap = input.parse().unwrap();
+
+        assert_eq!(map.calculate_ghost_steps(), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(2, 3, 1, 4), Some((5, 12)));
+        assert_eq!(crt(1, 4, 3, 6), Some((9, 12)));
+        assert_eq!(crt(0, 2, 1, 4), None);
+    }
+
+    #[test]
+    fn property_calculate_agrees_with_simulation_on_random_maps() {
+        let mut rng = Lcg::new(3);
+        let mut random = |bound: usize| rng.next(bound as u64) as usize;
+        for _ in 0..300 {
+            let nodes = 2 + random(7);
+            let instructions: String = (0..1 + random(4))
+                .map(|_| if random(2) == 0 { 'L' } else { 'R' })
+                .collect();
+            // labels end with A, Z or neither at random
+            let labels: Vec<String> = (0..nodes)
+                .map(|index| format!("{}{}", index, ["A", "Z", "X"][random(3)]))
+                .collect();
+            let lines: Vec<String> = labels
+                .iter()
+                .map(|label| {
+                    format!(
+                        "{} = ({}, {})",
+                        label,
+                        labels[random(nodes)],
+                        labels[random(nodes)]
+                    )
+                })
+                .collect();
+            let map: DesertMap = format!("{}\n\n{}", instructions, lines.join("\n"))
+                .parse()
+                .unwrap();
+            let starts: Vec<&str> = labels
+                .iter()
+                .filter(|label| label.ends_with('A'))
+                .map(String::as_str)
+                .collect();
+            if starts.is_empty() {
+                continue;
+            }
+            let ends: Vec<&str> = labels
+                .iter()
+                .filter(|label| label.ends_with('Z'))
+                .map(String::as_str)
+                .collect();
+
+            let max_steps = 10_000;
+            let expected = simulate(&map, &starts, &ends, max_steps);
+            let steps = map.calculate_ghost_steps();
+            match expected {
+                Some(_) => assert_eq!(steps, expected, "{:?}", lines),
+                None => assert!(steps.is_none_or(|steps| steps >= max_steps), "{:?}", lines),
+            }
+        }
+    }
+}
@@ -0,0 +1,442 @@
+use std::cmp::PartialEq;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use aoc::example::Example;
+use aoc::{Answer, Solution};
+
+mod dot;
+mod ghosts;
+
+/**
+
+Exercise https://adventofcode.com/2023/day/8
+
+
+**/
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Direction {
+    L,
+    R,
+}
+
+/// Compact identifier of a node, given in order of first appearance in the map.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Copy, Clone)]
+struct Destination {
+    right: NodeId,
+    left: NodeId,
+}
+
+impl Destination {
+    fn new(left: NodeId, right: NodeId) -> Self {
+        Destination { left, right }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingInstructions,
+    InvalidDirection(char),
+    /// Holds the 1-based line number of the malformed node.
+    MalformedNode(usize),
+    DuplicateNode(String),
+    /// A node is used as a destination but never defined.
+    UndefinedNode(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingInstructions => write!(f, "missing instructions"),
+            ParseError::InvalidDirection(direction) => {
+                write!(f, "invalid direction '{}'", direction)
+            }
+            ParseError::MalformedNode(line) => write!(f, "line {}: malformed node", line),
+            ParseError::DuplicateNode(label) => write!(f, "node {} defined twice", label),
+            ParseError::UndefinedNode(label) => write!(f, "node {} is never defined", label),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The destination cannot be reached from the start node.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Unreachable;
+
+impl fmt::Display for Unreachable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "destination is unreachable")
+    }
+}
+
+impl std::error::Error for Unreachable {}
+
+pub struct DesertMap {
+    instructions: Vec<Direction>,
+    /// Label of each node, indexed by `NodeId`.
+    labels: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    /// Destinations of each node, indexed by `NodeId`.
+    map: Vec<Destination>,
+}
+
+impl DesertMap {
+    pub fn node_id(&self, label: &str) -> Option<NodeId> {
+        self.ids.get(label).copied()
+    }
+
+    fn label(&self, node: NodeId) -> &str {
+        &self.labels[node.0]
+    }
+
+    fn next_node(&self, node: NodeId, direction: Direction) -> NodeId {
+        match direction {
+            Direction::L => self.map[node.0].left,
+            Direction::R => self.map[node.0].right,
+        }
+    }
+
+    ///
+    /// Follow the instructions from `start_node`, repeating them as many times as needed,
+    /// and count the steps until `end_node` is reached.
+    ///
+    pub fn calculate_steps_to_destination(
+        &self,
+        start_node: NodeId,
+        end_node: NodeId,
+    ) -> Result<u64, Unreachable> {
+        let steps = self.walk_to_destination(start_node, end_node, |node, direction| {
+            aoc::trace!(
+                "current node {}",
+                self.label(self.next_node(node, direction))
+            );
+        })?;
+        aoc::debug!("Reached destination: {}", self.label(end_node));
+        Ok(steps)
+    }
+
+    ///
+    /// Every move from `start_node` to `end_node`, as the node left and the direction taken.
+    ///
+    fn path_to_destination(
+        &self,
+        start_node: NodeId,
+        end_node: NodeId,
+    ) -> Result<Vec<(NodeId, Direction)>, Unreachable> {
+        let mut path: Vec<(NodeId, Direction)> = vec![];
+        self.walk_to_destination(start_node, end_node, |node, direction| {
+            path.push((node, direction))
+        })?;
+        Ok(path)
+    }
+
+    ///
+    /// Walk from `start_node` to `end_node`, calling `on_move` with the node left and the direction
+    /// taken at every step, and return the number of steps.
+    ///
+    /// The walk only depends on the current node and on the index of the next instruction,
+    /// so if the same pair shows up twice the walk is looping without ever reaching `end_node`.
+    ///
+    fn walk_to_destination(
+        &self,
+        start_node: NodeId,
+        end_node: NodeId,
+        mut on_move: impl FnMut(NodeId, Direction),
+    ) -> Result<u64, Unreachable> {
+        let mut visited: HashSet<(NodeId, usize)> = HashSet::new();
+        let mut steps: u64 = 0;
+        let mut current_node = start_node;
+        loop {
+            if current_node == end_node {
+                return Ok(steps);
+            }
+            let instruction_index = (steps % self.instructions.len() as u64) as usize;
+            if !visited.insert((current_node, instruction_index)) {
+                return Err(Unreachable);
+            }
+            let direction = self.instructions[instruction_index];
+            on_move(current_node, direction);
+            current_node = self.next_node(current_node, direction);
+            steps += 1;
+        }
+    }
+}
+
+///
+/// # Expected format:
+/// ```text
+/// LLR
+///
+/// AAA = (BBB, BBB)
+/// BBB = (AAA, ZZZ)
+/// ZZZ = (ZZZ, ZZZ)
+/// ```
+///
+/// Labels can be any sequence of characters without spaces, commas or parentheses.
+///
+impl FromStr for DesertMap {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input.lines().enumerate();
+        let instructions = lines
+            .next()
+            .map(|(_, line)| line.trim())
+            .filter(|line| !line.is_empty())
+            .ok_or(ParseError::MissingInstructions)?
+            .chars()
+            .map(|direction| match direction {
+                'L' => Ok(Direction::L),
+                'R' => Ok(Direction::R),
+                _ => Err(ParseError::InvalidDirection(direction)),
+            })
+            .collect::<Result<Vec<Direction>, ParseError>>()?;
+
+        let mut labels: Vec<String> = vec![];
+        let mut ids: HashMap<String, NodeId> = HashMap::new();
+        let mut intern = |label: &str| -> NodeId {
+            *ids.entry(label.to_string()).or_insert_with(|| {
+                labels.push(label.to_string());
+                NodeId(labels.len() - 1)
+            })
+        };
+
+        let mut destinations: Vec<(NodeId, Destination)> = vec![];
+        for (index, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+            let (label, left, right) =
+                parse_node(line).ok_or(ParseError::MalformedNode(index + 1))?;
+            let node = intern(label);
+            destinations.push((node, Destination::new(intern(left), intern(right))));
+        }
+
+        let mut map: Vec<Option<Destination>> = vec![None; labels.len()];
+        for (node, destination) in destinations {
+            if map[node.0].replace(destination).is_some() {
+                return Err(ParseError::DuplicateNode(labels[node.0].clone()));
+            }
+        }
+        let map = map
+            .into_iter()
+            .enumerate()
+            .map(|(index, destination)| {
+                destination.ok_or_else(|| ParseError::UndefinedNode(labels[index].clone()))
+            })
+            .collect::<Result<Vec<Destination>, ParseError>>()?;
+
+        Ok(DesertMap {
+            instructions,
+            labels,
+            ids,
+            map,
+        })
+    }
+}
+
+/// Split `AAA = (BBB, CCC)` into its three labels.
+fn parse_node(line: &str) -> Option<(&str, &str, &str)> {
+    let (label, destinations) = line.split_once('=')?;
+    let (left, right) = destinations
+        .trim()
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .split_once(',')?;
+    let (label, left, right) = (label.trim(), left.trim(), right.trim());
+    let is_valid = |label: &str| !label.is_empty() && !label.contains(char::is_whitespace);
+    if is_valid(label) && is_valid(left) && is_valid(right) {
+        Some((label, left, right))
+    } else {
+        None
+    }
+}
+
+pub fn sample() -> Example {
+    aoc::example!(8, "sample")
+}
+
+pub fn ghosts_sample() -> Example {
+    aoc::example!(8, "ghosts_sample")
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Parsed = DesertMap;
+
+    fn parse(input: &str) -> aoc::Result<Self::Parsed> {
+        Ok(input.parse::<DesertMap>()?)
+    }
+
+    /// Steps from `AAA` to `ZZZ`.
+    fn part1(map: &Self::Parsed) -> aoc::Result<Answer> {
+        let start_node = map.node_id("AAA").ok_or("missing node AAA")?;
+        let end_node = map.node_id("ZZZ").ok_or("missing node ZZZ")?;
+        Ok(map
+            .calculate_steps_to_destination(start_node, end_node)?
+            .into())
+    }
+
+    /// Steps until every ghost, starting from the nodes ending with `A`, is on a node ending with `Z`.
+    fn part2(map: &Self::Parsed) -> aoc::Result<Answer> {
+        let steps = map
+            .calculate_ghost_steps()
+            .ok_or("the ghosts never are on a destination at the same time within u64 steps")?;
+        Ok(steps.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_samples_when_solve_then_6_and_6() {
+        let map = Day8::parse(&sample().input).unwrap();
+        assert_eq!(Day8::part1(&map).unwrap(), Answer::Number(6));
+
+        let ghost_map = Day8::parse(&ghosts_sample().input).unwrap();
+        assert_eq!(Day8::part2(&ghost_map).unwrap(), Answer::Number(6));
+        assert_eq!(
+            Day8::part1(&ghost_map).unwrap_err().to_string(),
+            "missing node AAA"
+        );
+    }
+
+    #[test]
+    fn given_sample_when_parse_then_labels_are_interned() {
+        let map: DesertMap = sample().input.parse().unwrap();
+
+        assert_eq!(
+            map.instructions,
+            vec![Direction::L, Direction::L, Direction::R]
+        );
+        assert_eq!(map.labels, vec!["AAA", "BBB", "ZZZ"]);
+        let aaa = map.node_id("AAA").unwrap();
+        assert_eq!(map.label(map.next_node(aaa, Direction::R)), "BBB");
+        assert_eq!(map.node_id("CCC"), None);
+    }
+
+    #[test]
+    fn given_first_sample_when_calculate_steps_then_2() {
+        let map: DesertMap = aoc::example!(8, "first_sample").input.parse().unwrap();
+
+        let steps = map.calculate_steps_to_destination(
+            map.node_id("AAA").unwrap(),
+            map.node_id("ZZZ").unwrap(),
+        );
+
+        assert_eq!(steps, Ok(2));
+    }
+
+    #[test]
+    fn given_labels_of_any_length_when_parse_then_ok() {
+        let input = "L\n\nstart = (11A, end)\n11A = (end, end)\nend = (end, end)";
+        let map: DesertMap = input.parse().unwrap();
+
+        let steps = map.calculate_steps_to_destination(
+            map.node_id("start").unwrap(),
+            map.node_id("end").unwrap(),
+        );
+
+        assert_eq!(steps, Ok(2));
+    }
+
+    #[test]
+    fn given_invalid_input_when_parse_then_error() {
+        assert_eq!(
+            "".parse::<DesertMap>().err(),
+            Some(ParseError::MissingInstructions)
+        );
+        assert_eq!(
+            "LX\n\nAAA = (AAA, AAA)".parse::<DesertMap>().err(),
+            Some(ParseError::InvalidDirection('X'))
+        );
+        assert_eq!(
+            "L\n\nAAA = (AAA, AAA)\nBBB = AAA"
+                .parse::<DesertMap>()
+                .err(),
+            Some(ParseError::MalformedNode(4))
+        );
+        assert_eq!(
+            "L\n\nAAA = (BBB, AAA)".parse::<DesertMap>().err(),
+            Some(ParseError::UndefinedNode("BBB".to_string()))
+        );
+        assert_eq!(
+            "L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)"
+                .parse::<DesertMap>()
+                .err(),
+            Some(ParseError::DuplicateNode("AAA".to_string()))
+        );
+    }
+
+    #[test]
+    fn given_instructions_of_any_length_when_calculate_steps_then_cycle_them() {
+        // with 5 instructions a `% 3` cycle would turn right at step 4
+        let input = "LLLLR\n\nAAA = (BBB, ZZZ)\nBBB = (CCC, ZZZ)\nCCC = (DDD, ZZZ)\n\
+                     DDD = (EEE, ZZZ)\nEEE = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        let map: DesertMap = input.parse().unwrap();
+
+        let steps = map.calculate_steps_to_destination(
+            map.node_id("AAA").unwrap(),
+            map.node_id("EEE").unwrap(),
+        );
+
+        assert_eq!(steps, Ok(4));
+    }
+
+    #[test]
+    fn given_path_longer_than_20_passes_when_calculate_steps_then_full_count() {
+        let input = "L\n\nAAA = (BBB, AAA)\nBBB = (CCC, BBB)\nCCC = (ZZZ, CCC)\nZZZ = (ZZZ, ZZZ)";
+        let map: DesertMap = input.parse().unwrap();
+
+        // a long chain of nodes, to make sure the walk is neither capped nor recursive
+        let chain: Vec<String> = (0..100_000)
+            .map(|index| format!("N{} = (N{}, N{})", index, index + 1, index + 1))
+            .collect();
+        let long_input = format!("R\n\n{}\nN100000 = (N0, N0)", chain.join("\n"));
+        let long_map: DesertMap = long_input.parse().unwrap();
+
+        assert_eq!(
+            map.calculate_steps_to_destination(
+                map.node_id("AAA").unwrap(),
+                map.node_id("ZZZ").unwrap()
+            ),
+            Ok(3)
+        );
+        assert_eq!(
+            long_map.calculate_steps_to_destination(
+                long_map.node_id("N0").unwrap(),
+                long_map.node_id("N100000").unwrap()
+            ),
+            Ok(100_000)
+        );
+    }
+
+    #[test]
+    fn given_loop_without_destination_when_calculate_steps_then_unreachable() {
+        let input = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)";
+        let map: DesertMap = input.parse().unwrap();
+
+        let steps = map.calculate_steps_to_destination(
+            map.node_id("AAA").unwrap(),
+            map.node_id("ZZZ").unwrap(),
+        );
+
+        assert_eq!(steps, Err(Unreachable));
+    }
+
+    #[test]
+    fn given_start_is_destination_when_calculate_steps_then_0() {
+        let map: DesertMap = sample().input.parse().unwrap();
+        let node = map.node_id("BBB").unwrap();
+
+        assert_eq!(map.calculate_steps_to_destination(node, node), Ok(0));
+    }
+}
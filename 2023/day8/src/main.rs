@@ -1,162 +1,32 @@
-use std::cmp::PartialEq;
-use std::collections::HashMap;
+use aoc::{Part, Solution};
+use day8::{ghosts_sample, sample, Day8, DesertMap};
 
-/**
-
-Exercise https://adventofcode.com/2023/day/8
-
-
-**/
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-enum Direction {
-    L,
-    R,
-}
-
-#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
-enum NodeLabel {
-    AAA,
-    BBB,
-    CCC,
-    DDD,
-    EEE,
-    ZZZ,
-}
-
-struct Destination {
-    right: NodeLabel,
-    left: NodeLabel,
-}
-
-impl Destination {
-    fn new(left: NodeLabel, right: NodeLabel) -> Self {
-        Destination { left, right }
-    }
-}
-
-struct DesertMap {
-    instructions: Vec<Direction>,
-    map: HashMap<NodeLabel, Destination>,
-}
-
-impl DesertMap {
-    fn new(instructions: Vec<Direction>, map: HashMap<NodeLabel, Destination>) -> Self {
-        DesertMap { instructions, map }
-    }
+fn main() {
+    aoc::log::init();
 
-    fn calculate_steps_to_destination(&self, start_node: NodeLabel, end_node: NodeLabel) -> u32 {
-        if start_node == end_node {
-            return 0;
-        }
-        let mut current_node = start_node;
-        let max_iterations = 20;
-        let mut steps: u32 = 0;
-        for _ in 0..max_iterations {
-            for direction in &self.instructions {
-                match *direction {
-                    Direction::L => {
-                        current_node = self.map.get(&current_node).unwrap().left;
-                        steps += 1;
-                    }
-                    Direction::R => {
-                        current_node = self.map.get(&current_node).unwrap().right;
-                        steps += 1;
-                    }
-                }
-                println!("current node {:?}", &current_node);
+    let sample = sample();
+    let map: DesertMap = Day8::parse(&sample.input).unwrap();
 
-                if current_node == end_node {
-                    println!("Reached destination: {:?}", &end_node);
-                    return steps;
-                }
-            }
-        }
-        steps
+    // only the graph is printed, e.g. `cargo run -- --dot | dot -Tsvg > map.svg`
+    if std::env::args().any(|arg| arg == "--dot") {
+        let start_node = map.node_id("AAA").unwrap();
+        let end_node = map.node_id("ZZZ").unwrap();
+        println!("{}", map.to_dot(start_node, end_node, true));
+        return;
     }
 
-    fn calculate_steps_to_destination_recursive(
-        &self,
-        current_node: NodeLabel,
-        end_node: NodeLabel,
-        steps: u32,
-        direction: &Direction,
-    ) -> u32 {
-        if current_node == end_node {
-            return steps;
-        }
-        let next_direction: &Direction = self.instructions.get(((steps + 1) % 3) as usize).unwrap();
-        match direction {
-            Direction::L => {
-                let next_node = self.map.get(&current_node).unwrap().left;
-                self.calculate_steps_to_destination_recursive(
-                    next_node,
-                    end_node,
-                    steps + 1,
-                    next_direction,
-                )
-            }
-            Direction::R => {
-                let next_node = self.map.get(&current_node).unwrap().right;
-                self.calculate_steps_to_destination_recursive(
-                    next_node,
-                    end_node,
-                    steps + 1,
-                    next_direction,
-                )
-            }
-        }
-    }
-}
-
-fn main() {
     println!("AOC 2023 day 8.");
 
-    // LLR
-    //
-    // AAA = (BBB, BBB)
-    // BBB = (AAA, ZZZ)
-    // ZZZ = (ZZZ, ZZZ)
-    let instructions = vec![Direction::L, Direction::L, Direction::R];
-    let map = DesertMap::new(
-        instructions.clone(),
-        HashMap::from([
-            (
-                NodeLabel::AAA,
-                Destination::new(NodeLabel::BBB, NodeLabel::BBB),
-            ),
-            (
-                NodeLabel::BBB,
-                Destination::new(NodeLabel::AAA, NodeLabel::ZZZ),
-            ),
-            (
-                NodeLabel::ZZZ,
-                Destination::new(NodeLabel::ZZZ, NodeLabel::ZZZ),
-            ),
-        ]),
-    );
-
-    let steps = map.calculate_steps_to_destination(NodeLabel::AAA, NodeLabel::ZZZ);
+    let steps = Day8::part1(&map).unwrap();
     println!("Steps to destination: {}", steps);
-    assert_eq!(steps, 6);
-
-    let steps = map.calculate_steps_to_destination_recursive(
-        NodeLabel::AAA,
-        NodeLabel::ZZZ,
-        0,
-        &instructions.get(0).unwrap(),
+    assert_eq!(Some(steps), sample.answer(Part::One));
+
+    let ghosts_sample = ghosts_sample();
+    let ghost_map = Day8::parse(&ghosts_sample.input).unwrap();
+    let ghost_steps = Day8::part2(&ghost_map).unwrap();
+    println!(
+        "Steps for all the ghosts to reach a destination: {}",
+        ghost_steps
     );
-    println!("Steps to destination: {}", steps);
-    assert_eq!(steps, 6);
-}
-
-#[cfg(test)]
-mod tests {
-
-    // #[test]
-    // fn test_hand_ordering_less() {
-    //     let hand1 = Hand::new("32T3K", 0);
-    //     let hand2 = Hand::new("T55J5", 0);
-    //     assert_eq!(hand1 > hand2, false);
-    // }
+    assert_eq!(Some(ghost_steps), ghosts_sample.answer(Part::Two));
 }
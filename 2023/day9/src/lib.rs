@@ -0,0 +1,666 @@
+mod polynomial;
+
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use aoc::example::Example;
+use aoc::{Answer, Solution};
+
+pub use polynomial::{Polynomial, Rational};
+
+/*
+
+Exercise https://adventofcode.com/2023/day/9
+
+
+**/
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sequence {
+    pub numbers: Vec<i64>,
+}
+
+/// Why the next or previous value of a sequence cannot be predicted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PredictionError {
+    EmptySequence,
+    /// The differences run out of numbers before reaching a row of zeros, so the sequence
+    /// is not produced by a polynomial of a degree lower than its length minus one.
+    NotPolynomial,
+    /// A difference or a predicted value does not fit in an `i64`.
+    Overflow,
+}
+
+impl fmt::Display for PredictionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PredictionError::EmptySequence => write!(f, "the sequence is empty"),
+            PredictionError::NotPolynomial => {
+                write!(f, "the differences never reach a row of zeros")
+            }
+            PredictionError::Overflow => write!(f, "the values overflow a 64 bits integer"),
+        }
+    }
+}
+
+impl std::error::Error for PredictionError {}
+
+impl Sequence {
+    pub fn new(numbers: Vec<i64>) -> Self {
+        Sequence { numbers }
+    }
+
+    ///
+    /// Obtain a new array of differences until the last array is all zeros, starting from the numbers themselves.
+    ///
+    /// The last array always holds at least one zero: an array of differences that becomes empty first
+    /// is only "all zeros" because there is nothing left in it, and then the sequence is not a polynomial.
+    ///
+    fn difference_arrays(&self) -> Result<Vec<Vec<i64>>, PredictionError> {
+        if self.numbers.is_empty() {
+            return Err(PredictionError::EmptySequence);
+        }
+
+        // Store all the differences arrays. They will be needed to calculate the predicted values.
+        let mut arrays: Vec<Vec<i64>> = vec![];
+        arrays.push(self.numbers.clone());
+
+        loop {
+            let last_array = arrays.last().unwrap();
+            if last_array.iter().all(|x| *x == 0) {
+                break; // Stop if the last array is all zeros
+            }
+            if last_array.len() == 1 {
+                return Err(PredictionError::NotPolynomial);
+            }
+
+            let current_array = last_array
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]))
+                .collect::<Option<Vec<i64>>>()
+                .ok_or(PredictionError::Overflow)?;
+            arrays.push(current_array);
+        }
+        Ok(arrays)
+    }
+
+    pub fn predict_next_value(&self) -> Result<i64, PredictionError> {
+        let arrays = self.difference_arrays()?;
+
+        // predict the last value of all the obtained arrays iterating bottom up
+        // add the last value of the current array to the previous difference number to predict the next value of the current array
+        // the bottom array is all zeros, so the value following it is zero too
+        let mut predicted_value: i64 = 0;
+        for array in arrays.iter().rev() {
+            let current_last_value = array.last().unwrap();
+            predicted_value = current_last_value
+                .checked_add(predicted_value)
+                .ok_or(PredictionError::Overflow)?;
+        }
+
+        Ok(predicted_value)
+    }
+
+    pub fn predict_previous_value(&self) -> Result<i64, PredictionError> {
+        let arrays = self.difference_arrays()?;
+
+        // same as predict_next_value, but on the first values: the value before the first one of an array
+        // is its first value minus the value predicted before the first one of the array below
+        let mut predicted_value: i64 = 0;
+        for array in arrays.iter().rev() {
+            let current_first_value = array.first().unwrap();
+            predicted_value = current_first_value
+                .checked_sub(predicted_value)
+                .ok_or(PredictionError::Overflow)?;
+        }
+
+        Ok(predicted_value)
+    }
+
+    ///
+    /// Value of the sequence at `index`, where `0` is the first number: `-1` is the previous value
+    /// and `numbers.len()` is the next one.
+    ///
+    /// The `n` numbers are interpolated by the polynomial of degree `n - 1` going through them (Lagrange),
+    /// whose value at `x` is the sum of `numbers[i] * L(i)`, with
+    /// `L(i) = (-1)^(n - 1 - i) * C(x, i) * C(x - i - 1, n - 1 - i)` in generalized binomial coefficients.
+    /// For the next value, `L(i)` is the alternating binomial coefficient `(-1)^(n - 1 - i) * C(n, i)`.
+    ///
+    /// Each `L(i + 1)` is derived from `L(i)`, so this runs in O(n) without allocating, unlike the
+    /// difference arrays. When those arrays reach a row of zeros both agree, but this never reports
+    /// `NotPolynomial`: it extrapolates any sequence with the polynomial of the highest possible degree.
+    ///
+    pub fn extrapolate_closed_form(&self, index: i64) -> Result<i64, PredictionError> {
+        let n = self.numbers.len() as i128;
+        if n == 0 {
+            return Err(PredictionError::EmptySequence);
+        }
+        if (0..n).contains(&(index as i128)) {
+            return Ok(self.numbers[index as usize]);
+        }
+        let x = index as i128;
+
+        // L(0) = (-1)^(n - 1) * C(x - 1, n - 1), each partial product C(x - 1, k) being an integer
+        let mut coefficient: i128 = 1;
+        for k in 1..n {
+            coefficient = coefficient
+                .checked_mul(x - k)
+                .ok_or(PredictionError::Overflow)?
+                / k;
+        }
+        if (n - 1) % 2 == 1 {
+            coefficient = -coefficient;
+        }
+
+        let mut value: i128 = 0;
+        for (i, number) in self.numbers.iter().enumerate() {
+            let i = i as i128;
+            value = coefficient
+                .checked_mul(*number as i128)
+                .and_then(|term| value.checked_add(term))
+                .ok_or(PredictionError::Overflow)?;
+
+            if i + 1 < n {
+                // L(i + 1) = -L(i) * (x - i) * (n - 1 - i) / ((x - i - 1) * (i + 1)), an exact division
+                // as L(i + 1) is an integer; x - i - 1 is not zero since x is outside of the sequence
+                coefficient = coefficient
+                    .checked_mul(-(x - i))
+                    .and_then(|c| c.checked_mul(n - 1 - i))
+                    .ok_or(PredictionError::Overflow)?
+                    / ((x - i - 1) * (i + 1));
+            }
+        }
+
+        i64::try_from(value).map_err(|_| PredictionError::Overflow)
+    }
+
+    pub fn predict_next_value_closed_form(&self) -> Result<i64, PredictionError> {
+        self.extrapolate_closed_form(self.numbers.len() as i64)
+    }
+
+    pub fn predict_previous_value_closed_form(&self) -> Result<i64, PredictionError> {
+        self.extrapolate_closed_form(-1)
+    }
+
+    ///
+    /// The polynomial of minimal degree generating the numbers, found from the first number of each array of differences.
+    ///
+    /// The `n` numbers always fit a polynomial of degree at most `n - 1`, so unlike the difference arrays
+    /// this does not need a row of zeros: its values are those of `extrapolate_closed_form`. When there is
+    /// a row of zeros, its value at `numbers.len()` is also what `predict_next_value` returns, and at `-1`
+    /// what `predict_previous_value` returns.
+    ///
+    pub fn fit_polynomial(&self) -> Result<Polynomial, PredictionError> {
+        let Some(first_number) = self.numbers.first() else {
+            return Err(PredictionError::EmptySequence);
+        };
+
+        let mut first_differences = vec![*first_number];
+        let mut array = self.numbers.clone();
+        while array.len() > 1 && array.iter().any(|x| *x != 0) {
+            array = array
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]))
+                .collect::<Option<Vec<i64>>>()
+                .ok_or(PredictionError::Overflow)?;
+            first_differences.push(array[0]);
+        }
+        Polynomial::from_forward_differences(&first_differences)
+    }
+}
+
+pub struct OasisReport {
+    pub sequences: Vec<Sequence>,
+}
+
+impl OasisReport {
+    pub fn sum_predictions(
+        &self,
+        predict: impl Fn(&Sequence) -> Result<i64, PredictionError>,
+    ) -> Result<i64, PredictionError> {
+        self.sequences.iter().try_fold(0i64, |sum, seq| {
+            sum.checked_add(predict(seq)?)
+                .ok_or(PredictionError::Overflow)
+        })
+    }
+
+    pub fn calculate_prediction_sum(&self) -> Result<i64, PredictionError> {
+        self.sum_predictions(Sequence::predict_next_value)
+    }
+
+    pub fn calculate_previous_prediction_sum(&self) -> Result<i64, PredictionError> {
+        self.sum_predictions(Sequence::predict_previous_value)
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = ParseIntError;
+
+    /// Parse whitespace-separated numbers, e.g. `10 -3 7`.
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        line.split_whitespace()
+            .map(i64::from_str)
+            .collect::<Result<Vec<i64>, ParseIntError>>()
+            .map(Sequence::new)
+    }
+}
+
+///
+/// Parse one sequence per line, skipping blank lines.
+///
+pub fn parse_report(input: &str) -> Result<OasisReport, ParseIntError> {
+    let sequences = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Sequence::from_str)
+        .collect::<Result<Vec<Sequence>, ParseIntError>>()?;
+    Ok(OasisReport { sequences })
+}
+
+pub fn sample() -> Example {
+    aoc::example!(9, "sample")
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Parsed = OasisReport;
+
+    fn parse(input: &str) -> aoc::Result<Self::Parsed> {
+        Ok(parse_report(input)?)
+    }
+
+    fn part1(report: &Self::Parsed) -> aoc::Result<Answer> {
+        Ok(report.calculate_prediction_sum()?.into())
+    }
+
+    fn part2(report: &Self::Parsed) -> aoc::Result<Answer> {
+        Ok(report.calculate_previous_prediction_sum()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::test_util::Lcg;
+
+    use super::*;
+
+    #[test]
+    fn given_sample_when_solve_then_114_and_2() {
+        let report = Day9::parse(&sample().input).unwrap();
+
+        assert_eq!(Day9::part1(&report).unwrap(), Answer::Number(114));
+        assert_eq!(Day9::part2(&report).unwrap(), Answer::Number(2));
+    }
+
+    #[test]
+    fn given_sample_when_parse_then_sequences() {
+        let report = parse_report(&sample().input).unwrap();
+
+        assert_eq!(report.sequences.len(), 3);
+        assert_eq!(
+            report.sequences[2],
+            Sequence::new(vec![10, 13, 16, 21, 30, 45])
+        );
+    }
+
+    #[test]
+    fn given_negative_numbers_when_parse_then_ok() {
+        let sequence: Sequence = "  -4 0  -12 7 ".parse().unwrap();
+
+        assert_eq!(sequence, Sequence::new(vec![-4, 0, -12, 7]));
+    }
+
+    #[test]
+    fn given_invalid_number_when_parse_then_error() {
+        assert!("1 2 x".parse::<Sequence>().is_err());
+        assert!(parse_report("1 2\n3 4.5").is_err());
+    }
+
+    #[test]
+    fn given_decreasing_sequence_when_predict_then_negative_value() {
+        let sequence = Sequence::new(vec![10, 7, 4, 1, -2]);
+
+        assert_eq!(sequence.predict_next_value(), Ok(-5));
+    }
+
+    #[test]
+    fn given_negative_quadratic_sequence_when_predict_then_ok() {
+        // -n^2 for n in 0..5
+        let sequence = Sequence::new(vec![0, -1, -4, -9, -16]);
+
+        assert_eq!(sequence.predict_next_value(), Ok(-25));
+    }
+
+    #[test]
+    fn given_sequence_crossing_zero_when_predict_then_ok() {
+        let sequence: Sequence = "5 1 -1 -1 1 5".parse().unwrap();
+
+        assert_eq!(sequence.predict_next_value(), Ok(11));
+    }
+
+    #[test]
+    fn given_sample_when_calculate_prediction_sum_then_114() {
+        let report = parse_report(&sample().input).unwrap();
+
+        assert_eq!(report.calculate_prediction_sum(), Ok(114));
+    }
+
+    #[test]
+    fn given_sample_sequences_when_predict_previous_then_match_puzzle() {
+        let report = parse_report(&sample().input).unwrap();
+
+        let previous_values: Vec<Result<i64, PredictionError>> = report
+            .sequences
+            .iter()
+            .map(|sequence| sequence.predict_previous_value())
+            .collect();
+
+        assert_eq!(previous_values, vec![Ok(-3), Ok(0), Ok(5)]);
+    }
+
+    #[test]
+    fn given_sample_when_calculate_previous_prediction_sum_then_2() {
+        let report = parse_report(&sample().input).unwrap();
+
+        assert_eq!(report.calculate_previous_prediction_sum(), Ok(2));
+    }
+
+    #[test]
+    fn given_empty_sequence_when_predict_then_error() {
+        let sequence = Sequence::new(vec![]);
+
+        assert_eq!(
+            sequence.predict_next_value(),
+            Err(PredictionError::EmptySequence)
+        );
+        assert_eq!(
+            sequence.predict_previous_value(),
+            Err(PredictionError::EmptySequence)
+        );
+    }
+
+    #[test]
+    fn given_constant_zero_sequence_when_predict_then_zero() {
+        for numbers in [vec![0], vec![0, 0, 0, 0]] {
+            let sequence = Sequence::new(numbers);
+
+            assert_eq!(sequence.predict_next_value(), Ok(0));
+            assert_eq!(sequence.predict_previous_value(), Ok(0));
+        }
+    }
+
+    #[test]
+    fn given_constant_sequence_when_predict_then_same_value() {
+        let sequence = Sequence::new(vec![-7, -7]);
+
+        assert_eq!(sequence.predict_next_value(), Ok(-7));
+        assert_eq!(sequence.predict_previous_value(), Ok(-7));
+    }
+
+    #[test]
+    fn given_single_non_zero_value_when_predict_then_not_polynomial() {
+        let sequence = Sequence::new(vec![3]);
+
+        assert_eq!(
+            sequence.predict_next_value(),
+            Err(PredictionError::NotPolynomial)
+        );
+        assert_eq!(
+            sequence.predict_previous_value(),
+            Err(PredictionError::NotPolynomial)
+        );
+    }
+
+    #[test]
+    fn given_differences_that_never_reach_zero_when_predict_then_not_polynomial() {
+        // powers of two are their own differences
+        let sequence = Sequence::new(vec![1, 2, 4, 8, 16]);
+        assert_eq!(
+            sequence.predict_next_value(),
+            Err(PredictionError::NotPolynomial)
+        );
+
+        // two points are not enough to tell a line from anything else
+        let sequence = Sequence::new(vec![1, 2]);
+        assert_eq!(
+            sequence.predict_next_value(),
+            Err(PredictionError::NotPolynomial)
+        );
+    }
+
+    #[test]
+    fn given_line_with_three_points_when_predict_then_ok() {
+        let sequence = Sequence::new(vec![1, 2, 3]);
+
+        assert_eq!(sequence.predict_next_value(), Ok(4));
+        assert_eq!(sequence.predict_previous_value(), Ok(0));
+    }
+
+    #[test]
+    fn given_huge_values_when_predict_then_overflow() {
+        let sequence = Sequence::new(vec![i64::MIN, 0, i64::MAX]);
+        assert_eq!(
+            sequence.predict_next_value(),
+            Err(PredictionError::Overflow)
+        );
+
+        let sequence = Sequence::new(vec![i64::MAX - 2, i64::MAX - 1, i64::MAX]);
+        assert_eq!(
+            sequence.predict_next_value(),
+            Err(PredictionError::Overflow)
+        );
+        assert_eq!(sequence.predict_previous_value(), Ok(i64::MAX - 3));
+    }
+
+    #[test]
+    fn given_invalid_sequence_when_calculate_prediction_sum_then_error() {
+        let report = parse_report("0 3 6 9\n1 2 4 8").unwrap();
+
+        assert_eq!(
+            report.calculate_prediction_sum(),
+            Err(PredictionError::NotPolynomial)
+        );
+    }
+
+    /// The first `length` values `p(0), p(1), ...` of a random polynomial `p` of the given degree with small coefficients.
+    fn random_polynomial_sequence(rng: &mut Lcg, degree: u64, length: i64) -> Sequence {
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.next_signed(20)).collect();
+        let numbers = (0..length)
+            .map(|x| coefficients.iter().rev().fold(0, |value, c| value * x + c))
+            .collect();
+        Sequence::new(numbers)
+    }
+
+    #[test]
+    fn given_sample_when_closed_form_then_match_puzzle() {
+        let report = parse_report(&sample().input).unwrap();
+
+        assert_eq!(
+            report.sum_predictions(Sequence::predict_next_value_closed_form),
+            Ok(114)
+        );
+        assert_eq!(
+            report.sum_predictions(Sequence::predict_previous_value_closed_form),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn given_any_index_when_closed_form_then_value_of_the_polynomial() {
+        // n^2 - 3n + 1
+        let sequence = Sequence::new(vec![1, -1, -1, 1]);
+
+        for index in -10..20 {
+            assert_eq!(
+                sequence.extrapolate_closed_form(index),
+                Ok(index * index - 3 * index + 1),
+                "index {}",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn given_edge_cases_when_closed_form_then_ok() {
+        assert_eq!(
+            Sequence::new(vec![]).predict_next_value_closed_form(),
+            Err(PredictionError::EmptySequence)
+        );
+        assert_eq!(
+            Sequence::new(vec![3]).predict_next_value_closed_form(),
+            Ok(3)
+        );
+        assert_eq!(
+            Sequence::new(vec![1, 2]).predict_previous_value_closed_form(),
+            Ok(0)
+        );
+        // not a polynomial for the difference arrays, but the cubic going through the 4 points
+        assert_eq!(
+            Sequence::new(vec![1, 2, 4, 8]).predict_next_value_closed_form(),
+            Ok(15)
+        );
+        assert_eq!(
+            Sequence::new(vec![i64::MAX - 2, i64::MAX - 1, i64::MAX])
+                .predict_next_value_closed_form(),
+            Err(PredictionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn given_random_polynomials_when_closed_form_then_agree_with_difference_arrays() {
+        let mut rng = Lcg::new(9);
+        for _ in 0..2_000 {
+            let length = rng.next(13) as i64 + 1;
+            let degree = rng.next(length as u64);
+            let sequence = random_polynomial_sequence(&mut rng, degree, length);
+
+            let next = sequence.predict_next_value();
+            let previous = sequence.predict_previous_value();
+            if next.is_ok() {
+                assert_eq!(
+                    sequence.predict_next_value_closed_form(),
+                    next,
+                    "{:?}",
+                    sequence
+                );
+                assert_eq!(
+                    sequence.predict_previous_value_closed_form(),
+                    previous,
+                    "{:?}",
+                    sequence
+                );
+            } else {
+                // only a polynomial of degree length - 1 has no row of zeros
+                assert_eq!(next, Err(PredictionError::NotPolynomial));
+                assert_eq!(degree, length as u64 - 1);
+            }
+        }
+    }
+
+    #[test]
+    fn given_sample_when_fit_polynomial_then_formulas() {
+        let report = parse_report(&sample().input).unwrap();
+
+        let formulas: Vec<String> = report
+            .sequences
+            .iter()
+            .map(|sequence| sequence.fit_polynomial().unwrap().to_string())
+            .collect();
+
+        assert_eq!(
+            formulas,
+            vec![
+                "3x",
+                "(1/2)x^2 + (3/2)x + 1",
+                "(1/3)x^3 - x^2 + (11/3)x + 10"
+            ]
+        );
+    }
+
+    #[test]
+    fn given_sequences_without_row_of_zeros_when_fit_polynomial_then_highest_degree() {
+        let polynomial = Sequence::new(vec![1, 2]).fit_polynomial().unwrap();
+        assert_eq!(polynomial.to_string(), "x + 1");
+
+        let sequence = Sequence::new(vec![1, 2, 4, 8]);
+        let polynomial = sequence.fit_polynomial().unwrap();
+        assert_eq!(polynomial.degree(), 3);
+        assert_eq!(polynomial.evaluate(4), Ok(Rational::new(15, 1)));
+        assert_eq!(sequence.extrapolate_closed_form(4), Ok(15));
+    }
+
+    #[test]
+    fn given_empty_sequence_when_fit_polynomial_then_error() {
+        assert_eq!(
+            Sequence::new(vec![]).fit_polynomial(),
+            Err(PredictionError::EmptySequence)
+        );
+    }
+
+    #[test]
+    fn given_random_polynomials_when_fit_polynomial_then_agree_with_predictions() {
+        let mut rng = Lcg::new(45);
+        for _ in 0..500 {
+            let length = rng.next(13) as i64 + 1;
+            let degree = rng.next(length as u64);
+            let sequence = random_polynomial_sequence(&mut rng, degree, length);
+
+            let polynomial = sequence.fit_polynomial().unwrap();
+            assert!(polynomial.degree() as u64 <= degree, "{:?}", sequence);
+            for index in -3..length + 3 {
+                let value = polynomial.evaluate(index).unwrap();
+                assert!(value.is_integer(), "{:?} at {}", sequence, index);
+                assert_eq!(
+                    Ok(value.numerator as i64),
+                    sequence.extrapolate_closed_form(index),
+                    "{:?} at {}",
+                    sequence,
+                    index
+                );
+            }
+            if let Ok(next) = sequence.predict_next_value() {
+                assert_eq!(
+                    polynomial.evaluate(length),
+                    Ok(Rational::new(next as i128, 1))
+                );
+            } else {
+                assert_eq!(degree, length as u64 - 1);
+            }
+        }
+    }
+
+    /// Difference arrays against binomial sums, on sequences long enough for both to matter.
+    #[test]
+    #[ignore]
+    fn bench_predict_next_value_one_hundred_thousand_sequences() {
+        let mut rng = Lcg::new(2023);
+        let sequences: Vec<Sequence> = (0..100_000)
+            .map(|_| {
+                let degree = rng.next(12);
+                random_polynomial_sequence(&mut rng, degree, 13)
+            })
+            .collect();
+
+        let start = std::time::Instant::now();
+        let table: Vec<Result<i64, PredictionError>> =
+            sequences.iter().map(Sequence::predict_next_value).collect();
+        let difference_arrays = start.elapsed();
+
+        let start = std::time::Instant::now();
+        let closed_form: Vec<Result<i64, PredictionError>> = sequences
+            .iter()
+            .map(Sequence::predict_next_value_closed_form)
+            .collect();
+        let binomial_sums = start.elapsed();
+
+        assert_eq!(table, closed_form);
+        println!(
+            "100k sequences of 13 numbers: difference arrays {:?}, binomial sums {:?}",
+            difference_arrays, binomial_sums
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use std::fmt;
+
+use crate::PredictionError;
+
+/// A fraction in lowest terms, with a positive denominator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rational {
+    pub numerator: i128,
+    pub denominator: i128,
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Rational {
+    const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// Reduce `numerator / denominator`. The denominator is never zero: the callers only divide by
+    /// factorials and by products of denominators.
+    pub(crate) fn new(numerator: i128, denominator: i128) -> Self {
+        debug_assert_ne!(denominator, 0, "zero denominator in {}/0", numerator);
+        let divisor = gcd(numerator, denominator).max(1) * denominator.signum();
+        Rational {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    fn checked_add(self, other: Rational) -> Option<Rational> {
+        // the least common multiple keeps the denominators as small as possible
+        let lcm = (self.denominator / gcd(self.denominator, other.denominator))
+            .checked_mul(other.denominator)?;
+        let numerator = self
+            .numerator
+            .checked_mul(lcm / self.denominator)?
+            .checked_add(other.numerator.checked_mul(lcm / other.denominator)?)?;
+        Some(Rational::new(numerator, lcm))
+    }
+
+    fn checked_mul_integer(self, factor: i128) -> Option<Rational> {
+        // simplify first, so that the numerator only grows as much as needed
+        let divisor = gcd(factor, self.denominator).max(1);
+        Some(Rational::new(
+            self.numerator.checked_mul(factor / divisor)?,
+            self.denominator / divisor,
+        ))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+///
+/// A polynomial with rational coefficients, in the index `x` of the sequence (`0` for the first number).
+///
+/// It is displayed highest power first, e.g. `(1/2)x^2 + (3/2)x + 1`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// `coefficients[k]` multiplies `x^k`. There is no trailing zero, except for the zero polynomial.
+    pub coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    ///
+    /// Build the polynomial from the first number of each array of differences (Newton's forward formula):
+    /// `p(x) = d0 + d1 * C(x, 1) + d2 * C(x, 2) + ...`, where `C(x, k) = x(x - 1)...(x - k + 1) / k!`.
+    ///
+    pub fn from_forward_differences(differences: &[i64]) -> Result<Self, PredictionError> {
+        // x(x - 1)...(x - k + 1), whose coefficients are integers, and k!
+        let mut falling_factorial: Vec<i128> = vec![1];
+        let mut factorial: i128 = 1;
+        let mut coefficients = vec![Rational::ZERO];
+
+        for (k, difference) in differences.iter().enumerate() {
+            if k > 0 {
+                falling_factorial = multiply_by_root(&falling_factorial, k as i128 - 1)
+                    .ok_or(PredictionError::Overflow)?;
+                factorial = factorial
+                    .checked_mul(k as i128)
+                    .ok_or(PredictionError::Overflow)?;
+            }
+            if *difference == 0 {
+                continue;
+            }
+
+            coefficients.resize(falling_factorial.len(), Rational::ZERO);
+            for (coefficient, term) in coefficients.iter_mut().zip(&falling_factorial) {
+                *coefficient = Rational::new(*term, factorial)
+                    .checked_mul_integer(*difference as i128)
+                    .and_then(|term| coefficient.checked_add(term))
+                    .ok_or(PredictionError::Overflow)?;
+            }
+        }
+
+        while coefficients.len() > 1 && *coefficients.last().unwrap() == Rational::ZERO {
+            coefficients.pop();
+        }
+        Ok(Polynomial { coefficients })
+    }
+
+    /// Highest power of `x` with a non-zero coefficient, `0` for constant polynomials (including zero).
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Value at `x`, with Horner's method.
+    pub fn evaluate(&self, x: i64) -> Result<Rational, PredictionError> {
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(Rational::ZERO, |value, coefficient| {
+                value
+                    .checked_mul_integer(x as i128)
+                    .and_then(|value| value.checked_add(*coefficient))
+            })
+            .ok_or(PredictionError::Overflow)
+    }
+}
+
+/// Multiply the polynomial by `(x - root)`.
+fn multiply_by_root(coefficients: &[i128], root: i128) -> Option<Vec<i128>> {
+    let mut product = vec![0i128; coefficients.len() + 1];
+    for (power, coefficient) in coefficients.iter().enumerate() {
+        product[power + 1] = product[power + 1].checked_add(*coefficient)?;
+        product[power] = product[power].checked_sub(coefficient.checked_mul(root)?)?;
+    }
+    Some(product)
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.coefficients.len() == 1 {
+            return write!(f, "{}", self.coefficients[0]);
+        }
+
+        let mut first = true;
+        for (power, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.numerator == 0 {
+                continue;
+            }
+            let sign = match (first, coefficient.numerator < 0) {
+                (true, false) => "",
+                (true, true) => "-",
+                (false, false) => " + ",
+                (false, true) => " - ",
+            };
+            first = false;
+
+            let magnitude = Rational::new(coefficient.numerator.abs(), coefficient.denominator);
+            // the coefficient of a power of x is omitted when it is 1, and wrapped when it is a fraction
+            let factor = match (power, magnitude.is_integer()) {
+                (0, _) => magnitude.to_string(),
+                (_, true) if magnitude.numerator == 1 => String::new(),
+                (_, true) => magnitude.to_string(),
+                (_, false) => format!("({})", magnitude),
+            };
+            let variable = match power {
+                0 => String::new(),
+                1 => "x".to_string(),
+                power => format!("x^{}", power),
+            };
+            write!(f, "{}{}{}", sign, factor, variable)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integers(coefficients: &[i128]) -> Vec<Rational> {
+        coefficients.iter().map(|c| Rational::new(*c, 1)).collect()
+    }
+
+    #[test]
+    fn given_fractions_when_new_then_lowest_terms() {
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+        assert_eq!(Rational::new(-3, 2).to_string(), "-3/2");
+        assert_eq!(
+            Rational::new(1, 6).checked_add(Rational::new(1, 3)),
+            Some(Rational::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn given_triangular_differences_when_from_forward_differences_then_fractions() {
+        // 1 3 6 10 15 21: (x + 1)(x + 2) / 2
+        let polynomial = Polynomial::from_forward_differences(&[1, 2, 1, 0]).unwrap();
+
+        assert_eq!(
+            polynomial.coefficients,
+            vec![
+                Rational::new(1, 1),
+                Rational::new(3, 2),
+                Rational::new(1, 2)
+            ]
+        );
+        assert_eq!(polynomial.degree(), 2);
+        assert_eq!(polynomial.to_string(), "(1/2)x^2 + (3/2)x + 1");
+        assert_eq!(polynomial.evaluate(6), Ok(Rational::new(28, 1)));
+    }
+
+    #[test]
+    fn given_polynomials_when_display_then_pretty_formula() {
+        let formula = |coefficients: &[i128]| {
+            Polynomial {
+                coefficients: integers(coefficients),
+            }
+            .to_string()
+        };
+
+        assert_eq!(formula(&[0]), "0");
+        assert_eq!(formula(&[-7]), "-7");
+        assert_eq!(formula(&[1, -3, 1]), "x^2 - 3x + 1");
+        assert_eq!(formula(&[0, 0, 0, -1]), "-x^3");
+        assert_eq!(formula(&[-4, 1]), "x - 4");
+    }
+
+    #[test]
+    fn given_zero_differences_when_from_forward_differences_then_zero_polynomial() {
+        let polynomial = Polynomial::from_forward_differences(&[0]).unwrap();
+
+        assert_eq!(polynomial.coefficients, integers(&[0]));
+        assert_eq!(polynomial.degree(), 0);
+        assert_eq!(polynomial.evaluate(-12), Ok(Rational::ZERO));
+    }
+
+    #[test]
+    fn given_huge_coefficients_when_multiply_by_root_then_none() {
+        assert_eq!(multiply_by_root(&[3, 1], -2), Some(vec![6, 5, 1]));
+        assert_eq!(multiply_by_root(&[1, i128::MAX], 3), None);
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use aoc::Part;
+
+///
+/// Accepted answers for the personal inputs, read from a small subset of TOML.
+///
+/// # Expected format:
+///
+/// ```text
+/// # comments and blank lines are ignored
+/// [day6]
+/// part1 = 288
+/// part2 = "71503"
+/// ```
+///
+/// Numbers can be written with or without quotes, and answers are compared as text.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+pub fn parse_answers(content: &str) -> Result<Answers, String> {
+    let mut answers = BTreeMap::new();
+    let mut day = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            let number = section
+                .trim()
+                .strip_prefix("day")
+                .and_then(|number| number.parse::<u8>().ok())
+                .filter(|number| (1..=25).contains(number))
+                .ok_or_else(|| {
+                    format!(
+                        "line {}: expected [day<1-25>], found [{}]",
+                        line_number, section
+                    )
+                })?;
+            day = Some(number);
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected 'part<n> = <answer>'", line_number))?;
+        let day = day
+            .ok_or_else(|| format!("line {}: answer outside of a [day<n>] section", line_number))?;
+        let part = match key.trim() {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            other => return Err(format!("line {}: unknown part '{}'", line_number, other)),
+        };
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value);
+        if value.is_empty() {
+            return Err(format!("line {}: empty answer", line_number));
+        }
+        if answers.insert((day, part), value.to_string()).is_some() {
+            return Err(format!(
+                "line {}: day {} {} answered twice",
+                line_number, day, part
+            ));
+        }
+    }
+
+    Ok(Answers { answers })
+}
+
+///
+/// Read the answers file given with `--answers`, or else the default one.
+///
+/// Only a missing default file means that no answer is recorded yet: a file that was asked for
+/// must be readable.
+///
+pub fn read_answers(file: Option<&Path>, default_file: &Path) -> Result<Answers, String> {
+    let path = file.unwrap_or(default_file);
+    match std::fs::read_to_string(path) {
+        Ok(content) => parse_answers(&content),
+        Err(error) if error.kind() == ErrorKind::NotFound && file.is_none() => {
+            Ok(Answers::default())
+        }
+        Err(error) => Err(error.to_string()),
+    }
+    .map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_answers_file_when_parse_then_answers_by_day_and_part() {
+        let content = "# accepted answers\n\n[day6]\npart1 = 288 # sample\npart2 = \"71503\"\n\n[ day9 ]\npart2 = -3\n";
+
+        let answers = parse_answers(content).unwrap();
+
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(6, Part::One), Some("288"));
+        assert_eq!(answers.get(6, Part::Two), Some("71503"));
+        assert_eq!(answers.get(9, Part::One), None);
+        assert_eq!(answers.get(9, Part::Two), Some("-3"));
+    }
+
+    #[test]
+    fn given_invalid_answers_files_when_parse_then_line_is_reported() {
+        let error = |content: &str| parse_answers(content).unwrap_err();
+
+        assert_eq!(
+            error("part1 = 3"),
+            "line 1: answer outside of a [day<n>] section"
+        );
+        assert_eq!(error("[day1]\n\npart3 = 3"), "line 3: unknown part 'part3'");
+        assert_eq!(
+            error("[day26]"),
+            "line 1: expected [day<1-25>], found [day26]"
+        );
+        assert_eq!(
+            error("[day1]\npart1"),
+            "line 2: expected 'part<n> = <answer>'"
+        );
+        assert_eq!(error("[day1]\npart1 = \"\""), "line 2: empty answer");
+        assert_eq!(
+            error("[day1]\npart1 = 1\n[day1]\npart1 = 2"),
+            "line 4: day 1 part 1 answered twice"
+        );
+    }
+
+    #[test]
+    fn given_missing_answers_file_when_read_then_empty_only_for_the_default_file() {
+        let missing = std::env::temp_dir().join("runner-missing-answers.toml");
+
+        assert_eq!(read_answers(None, &missing), Ok(Answers::default()));
+        assert!(read_answers(Some(&missing), &missing)
+            .unwrap_err()
+            .starts_with(&missing.display().to_string()));
+    }
+
+    #[test]
+    fn given_unreadable_default_answers_file_when_read_then_error() {
+        // a directory exists but cannot be read as a file
+        let directory = std::env::temp_dir();
+
+        assert!(read_answers(None, &directory).is_err());
+    }
+}
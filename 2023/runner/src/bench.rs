@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use aoc::Solution;
+
+use crate::catch_panic;
+
+/// Time spent in each step of a solution, the median of several runs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+
+    fn steps(&self) -> [Duration; 4] {
+        [self.parse, self.part1, self.part2, self.total()]
+    }
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+/// Parse the input and solve both parts `iterations` times, timing every step separately.
+///
+/// A panicking solution is reported as an error, so that the other days are still timed.
+pub fn measure<S: Solution>(input: &str, iterations: u32) -> aoc::Result<Timings> {
+    catch_panic(|| measure_steps::<S>(input, iterations))
+}
+
+fn measure_steps<S: Solution>(input: &str, iterations: u32) -> aoc::Result<Timings> {
+    let (mut parse, mut part1, mut part2) = (vec![], vec![], vec![]);
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(S::part1(&parsed)?);
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(S::part2(&parsed)?);
+        part2.push(start.elapsed());
+    }
+    Ok(Timings {
+        parse: median(parse),
+        part1: median(part1),
+        part2: median(part2),
+    })
+}
+
+/// Timings of a previous run, by day.
+pub type Baseline = BTreeMap<u8, Timings>;
+
+/// Timings of the current run by day, or the error of the days that failed.
+pub type BenchResults = BTreeMap<u8, Result<Timings, String>>;
+
+///
+/// Save the timings, one day per line, in nanoseconds.
+///
+/// # Expected format:
+///
+/// ```text
+/// # day parse part1 part2
+/// 9 41250 3708 3583
+/// ```
+///
+pub fn format_baseline(timings: &Baseline) -> String {
+    let mut lines = vec!["# day parse part1 part2".to_string()];
+    for (day, timing) in timings {
+        lines.push(format!(
+            "{} {} {} {}",
+            day,
+            timing.parse.as_nanos(),
+            timing.part1.as_nanos(),
+            timing.part2.as_nanos()
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+pub fn parse_baseline(content: &str) -> Result<Baseline, String> {
+    let mut baseline = Baseline::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let values = line
+            .split_whitespace()
+            .map(|value| value.parse::<u64>())
+            .collect::<Result<Vec<u64>, _>>()
+            .ok()
+            .filter(|values| values.len() == 4 && values[0] <= u8::MAX as u64)
+            .ok_or_else(|| {
+                format!(
+                    "line {}: expected '<day> <parse> <part1> <part2>'",
+                    index + 1
+                )
+            })?;
+        baseline.insert(
+            values[0] as u8,
+            Timings {
+                parse: Duration::from_nanos(values[1]),
+                part1: Duration::from_nanos(values[2]),
+                part2: Duration::from_nanos(values[3]),
+            },
+        );
+    }
+    Ok(baseline)
+}
+
+/// Duration with 3 significant digits and the most readable unit, e.g. `12.3µs`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    let decimals = match value {
+        value if value < 10.0 => 2,
+        value if value < 100.0 => 1,
+        _ => 0,
+    };
+    format!("{:.*}{}", decimals, value, unit)
+}
+
+/// Relative change from the baseline, e.g. `+12%`, positive when slower.
+fn format_change(duration: Duration, baseline: Duration) -> String {
+    if baseline.is_zero() {
+        return "n/a".to_string();
+    }
+    let change = (duration.as_nanos() as f64 / baseline.as_nanos() as f64 - 1.0) * 100.0;
+    format!("{:+.0}%", change)
+}
+
+///
+/// Render the timings as a table, e.g.
+///
+/// ```text
+/// day    parse   part 1   part 2    total
+///   9  41.2µs   3.71µs   3.58µs   48.5µs
+/// ```
+///
+/// With a baseline, each duration is followed by its change, for the days found in the baseline.
+/// A day that failed shows `FAILED` instead of its timings.
+///
+pub fn format_table(results: &BenchResults, baseline: Option<&Baseline>) -> String {
+    let headers = ["day", "parse", "part 1", "part 2", "total"];
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|(day, result)| {
+            let Ok(timing) = result else {
+                return vec![day.to_string(), "FAILED".to_string()];
+            };
+            let previous = baseline.and_then(|baseline| baseline.get(day));
+            let mut row = vec![day.to_string()];
+            for (index, duration) in timing.steps().iter().enumerate() {
+                row.push(match previous {
+                    Some(previous) => format!(
+                        "{} ({})",
+                        format_duration(*duration),
+                        format_change(*duration, previous.steps()[index])
+                    ),
+                    None => format_duration(*duration),
+                });
+            }
+            row
+        })
+        .collect();
+
+    let mut widths = headers.map(|header| header.chars().count());
+    for row in &rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+    // `{:>width$}` counts chars, so `µ` is aligned like any other letter
+    let format_row = |row: Vec<String>| -> String {
+        row.iter()
+            .zip(widths)
+            .map(|(value, width)| format!("{:>width$}", value, width = width))
+            .collect::<Vec<String>>()
+            .join("  ")
+    };
+
+    let mut lines = vec![format_row(headers.map(str::to_string).to_vec())];
+    lines.extend(rows.into_iter().map(format_row));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Answer;
+
+    use super::*;
+
+    fn timings(parse: u64, part1: u64, part2: u64) -> Timings {
+        Timings {
+            parse: Duration::from_nanos(parse),
+            part1: Duration::from_nanos(part1),
+            part2: Duration::from_nanos(part2),
+        }
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 0;
+
+        type Parsed = usize;
+
+        fn parse(input: &str) -> aoc::Result<Self::Parsed> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &Self::Parsed) -> aoc::Result<Answer> {
+            Ok((*lines).into())
+        }
+
+        fn part2(_lines: &Self::Parsed) -> aoc::Result<Answer> {
+            Err("no part 2".into())
+        }
+    }
+
+    #[test]
+    fn given_failing_solution_when_measure_then_error() {
+        assert_eq!(
+            measure::<Lines>("a\nb", 3).unwrap_err().to_string(),
+            "no part 2"
+        );
+    }
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const DAY: u8 = 0;
+
+        type Parsed = ();
+
+        fn parse(_input: &str) -> aoc::Result<Self::Parsed> {
+            panic!("index out of bounds")
+        }
+
+        fn part1(_parsed: &Self::Parsed) -> aoc::Result<Answer> {
+            Ok(Answer::Unsolved)
+        }
+
+        fn part2(_parsed: &Self::Parsed) -> aoc::Result<Answer> {
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    #[test]
+    fn given_panicking_solution_when_measure_then_error() {
+        assert_eq!(
+            measure::<Panicking>("a", 3).unwrap_err().to_string(),
+            "panicked: index out of bounds"
+        );
+    }
+
+    #[test]
+    fn given_durations_when_format_then_readable_units() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+        assert_eq!(format_duration(Duration::from_nanos(41_250)), "41.2µs");
+        assert_eq!(format_duration(Duration::from_nanos(3_708)), "3.71µs");
+        assert_eq!(format_duration(Duration::from_millis(1_500)), "1.50s");
+    }
+
+    #[test]
+    fn given_timings_when_save_and_load_baseline_then_same_timings() {
+        let baseline = Baseline::from([
+            (6, timings(1_000, 20, 3)),
+            (9, timings(41_250, 3_708, 3_583)),
+        ]);
+
+        let content = format_baseline(&baseline);
+
+        assert_eq!(
+            content,
+            "# day parse part1 part2\n6 1000 20 3\n9 41250 3708 3583\n"
+        );
+        assert_eq!(parse_baseline(&content), Ok(baseline));
+    }
+
+    #[test]
+    fn given_invalid_baseline_when_parse_then_line_is_reported() {
+        assert_eq!(
+            parse_baseline("# day parse part1 part2\n9 1 2"),
+            Err("line 2: expected '<day> <parse> <part1> <part2>'".to_string())
+        );
+        assert!(parse_baseline("9 1 2 x").is_err());
+    }
+
+    #[test]
+    fn given_baseline_when_format_table_then_changes() {
+        let current = BenchResults::from([
+            (6, Ok(timings(1_000, 20, 30))),
+            (9, Ok(timings(2_000, 10, 10))),
+        ]);
+        let baseline = Baseline::from([(6, timings(500, 20, 40))]);
+
+        assert_eq!(
+            format_table(&current, Some(&baseline)),
+            "day           parse        part 1         part 2          total\n\
+             \x20 6  1.00µs (+100%)  20.0ns (+0%)  30.0ns (-25%)  1.05µs (+88%)\n\
+             \x20 9          2.00µs        10.0ns         10.0ns         2.02µs"
+        );
+    }
+
+    #[test]
+    fn given_failed_day_when_format_table_then_failed_row() {
+        let results = BenchResults::from([
+            (8, Err("panicked: index out of bounds".to_string())),
+            (9, Ok(timings(2_000, 10, 10))),
+        ]);
+
+        assert_eq!(
+            format_table(&results, None),
+            "day   parse  part 1  part 2   total\n\
+             \x20 8  FAILED\n\
+             \x20 9  2.00µs  10.0ns  10.0ns  2.02µs"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub use aoc::example::{parse_example, Example};
+use aoc::Part;
+
+use crate::{workspace_dir, Puzzle};
+
+/// Outcome of one part of one example that did not give the expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub day: u8,
+    pub example: String,
+    pub part: Part,
+    pub expected: String,
+    /// The answer, or the error of the solution.
+    pub actual: String,
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} {} {}: expected {}, got {}",
+            self.day, self.example, self.part, self.expected, self.actual
+        )
+    }
+}
+
+/// Directory with the examples of a day, e.g. `examples/day9`.
+pub fn examples_dir(day: u8) -> PathBuf {
+    workspace_dir().join("examples").join(format!("day{}", day))
+}
+
+/// Examples of a day, sorted by file name.
+pub fn load_examples(dir: &Path) -> Result<Vec<Example>, String> {
+    let entries = fs::read_dir(dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy();
+            let content =
+                fs::read_to_string(path).map_err(|error| format!("{}: {}", name, error))?;
+            parse_example(&name, &content)
+        })
+        .collect()
+}
+
+/// Run every answered part of the examples, returning the ones that do not match.
+pub fn check_examples(puzzle: &Puzzle, examples: &[Example]) -> Vec<Failure> {
+    let mut failures = vec![];
+    for example in examples {
+        for (part, expected) in &example.expected {
+            let actual = match (puzzle.solve_part)(&example.input, *part) {
+                Ok(answer) => answer.to_string(),
+                Err(error) => format!("error: {}", error),
+            };
+            if actual != *expected {
+                failures.push(Failure {
+                    day: puzzle.day,
+                    example: example.name.clone(),
+                    part: *part,
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Answer;
+
+    use super::*;
+
+    #[test]
+    fn given_wrong_answer_when_check_examples_then_failure() {
+        let puzzle = Puzzle {
+            day: 0,
+            solve_part: |input, _| Ok(Answer::from(input.len())),
+            measure: |_, _| Err("not measured".into()),
+        };
+        let example = parse_example("sample", "part1: 3\npart2: 4\n\nabc").unwrap();
+
+        let failures = check_examples(&puzzle, &[example]);
+
+        assert_eq!(
+            failures
+                .iter()
+                .map(|failure| failure.to_string())
+                .collect::<Vec<String>>(),
+            vec!["day 0 sample part 2: expected 4, got 3"]
+        );
+    }
+}
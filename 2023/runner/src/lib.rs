@@ -0,0 +1,90 @@
+//!
+//! Every 2023 solution behind a single registry, so that they can all be run, checked and timed
+//! the same way.
+//!
+
+use std::any::Any;
+use std::fs;
+use std::panic::UnwindSafe;
+use std::path::{Path, PathBuf};
+
+use aoc::{solve_part, Answer, Part, Solution};
+
+use crate::bench::{measure, Timings};
+
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod verify;
+
+/// A registered day, with its solution erased behind a function pointer.
+pub struct Puzzle {
+    pub day: u8,
+    pub solve_part: fn(&str, Part) -> aoc::Result<Answer>,
+    /// Time parse, part 1 and part 2 on an input, over the given number of runs.
+    pub measure: fn(&str, u32) -> aoc::Result<Timings>,
+}
+
+impl Puzzle {
+    fn of<S: Solution>() -> Self {
+        Puzzle {
+            day: S::DAY,
+            solve_part: solve_part::<S>,
+            measure: measure::<S>,
+        }
+    }
+}
+
+/// Every solved day, in order.
+pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<day1::Day1>(),
+        Puzzle::of::<day2::Day2>(),
+        Puzzle::of::<day3::Day3>(),
+        Puzzle::of::<day4::Day4>(),
+        Puzzle::of::<day5::Day5>(),
+        Puzzle::of::<day6::Day6>(),
+        Puzzle::of::<day7::Day7>(),
+        Puzzle::of::<day8::Day8>(),
+        Puzzle::of::<day9::Day9>(),
+    ]
+}
+
+/// Run a solution step, turning a panic into an error.
+pub(crate) fn catch_panic<T>(step: impl FnOnce() -> aoc::Result<T> + UnwindSafe) -> aoc::Result<T> {
+    std::panic::catch_unwind(step).unwrap_or_else(|payload| Err(panic_message(payload).into()))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown cause".to_string());
+    format!("panicked: {}", message)
+}
+
+/// Root of the workspace, where the data files are.
+pub fn workspace_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Personal input of a day, e.g. `inputs/day9.txt`, if it exists.
+pub fn read_input(dir: &Path, day: u8) -> Option<String> {
+    fs::read_to_string(dir.join(format!("day{}.txt", day))).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_registry_when_puzzles_then_one_per_day_in_order() {
+        let days: Vec<u8> = puzzles().iter().map(|puzzle| puzzle.day).collect();
+
+        assert_eq!(days, (1..=9).collect::<Vec<u8>>());
+    }
+}
@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use runner::answers::read_answers;
+use runner::bench::{format_baseline, format_table, parse_baseline, Baseline, BenchResults};
+use runner::examples::{examples_dir, load_examples};
+use runner::verify::verify;
+use runner::{puzzles, read_input, workspace_dir, Puzzle};
+
+const USAGE: &str = "usage:
+  runner verify [--day <n>] [--inputs <dir>] [--answers <file>]
+  runner bench [--day <n>] [--inputs <dir>] [--iterations <n>] [--save <file>] [--compare <file>]
+diagnostics: --log <error|warn|info|debug|trace>, --verbose or AOC_LOG=<level>";
+
+fn main() -> ExitCode {
+    aoc::log::init();
+
+    // e.g. `cargo run -p runner -- verify --day 9`
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let option = |name: &str| {
+        args.iter()
+            .position(|arg| arg == name)
+            .and_then(|index| args.get(index + 1))
+    };
+
+    let selected_puzzles: Vec<Puzzle> = match option("--day").map(|day| day.parse::<u8>()) {
+        None => puzzles(),
+        Some(Ok(day)) => puzzles()
+            .into_iter()
+            .filter(|puzzle| puzzle.day == day)
+            .collect(),
+        Some(Err(_)) => vec![],
+    };
+    // an unknown day would otherwise run nothing and succeed
+    if selected_puzzles.is_empty() {
+        let days: Vec<String> = puzzles()
+            .iter()
+            .map(|puzzle| puzzle.day.to_string())
+            .collect();
+        eprintln!("--day expects one of {}\n{}", days.join(", "), USAGE);
+        return ExitCode::FAILURE;
+    }
+    let inputs_dir = option("--inputs").map_or(workspace_dir().join("inputs"), PathBuf::from);
+
+    let result = match args.first().map(String::as_str) {
+        Some("verify") => {
+            let answers_file = option("--answers").map(PathBuf::from);
+            run_verify(&selected_puzzles, &inputs_dir, answers_file.as_deref())
+        }
+        // e.g. `cargo run --release -p runner -- bench --save baseline.txt`
+        Some("bench") => match option("--iterations").map_or(Ok(10), |n| n.parse::<u32>()) {
+            Ok(iterations) => run_bench(
+                &selected_puzzles,
+                &inputs_dir,
+                iterations,
+                option("--save").map(PathBuf::from),
+                option("--compare").map(PathBuf::from),
+            ),
+            Err(_) => Err(USAGE.to_string()),
+        },
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_verify(
+    puzzles: &[Puzzle],
+    inputs_dir: &Path,
+    answers_file: Option<&Path>,
+) -> Result<ExitCode, String> {
+    let answers = read_answers(answers_file, &workspace_dir().join("answers.toml"))?;
+
+    let mut failed = false;
+    for puzzle in puzzles {
+        let input = read_input(inputs_dir, puzzle.day);
+        for (part, status) in verify(puzzle, input.as_deref(), &answers) {
+            println!("day {:>2} {}: {}", puzzle.day, part, status);
+            failed |= status.is_failure();
+        }
+    }
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Time every day on its personal input, or on its first example when there is none.
+fn run_bench(
+    puzzles: &[Puzzle],
+    inputs_dir: &Path,
+    iterations: u32,
+    save: Option<PathBuf>,
+    compare: Option<PathBuf>,
+) -> Result<ExitCode, String> {
+    let baseline = match &compare {
+        Some(file) => Some(
+            std::fs::read_to_string(file)
+                .map_err(|error| error.to_string())
+                .and_then(|content| parse_baseline(&content))
+                .map_err(|error| format!("{}: {}", file.display(), error))?,
+        ),
+        None => None,
+    };
+
+    let mut results = BenchResults::new();
+    for puzzle in puzzles {
+        let input = match read_input(inputs_dir, puzzle.day) {
+            Some(input) => input,
+            None => {
+                let example = load_examples(&examples_dir(puzzle.day))?
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| format!("day {}: no input and no example", puzzle.day))?;
+                aoc::warn!(
+                    "day {}: no personal input, timing the example {}",
+                    puzzle.day,
+                    example.name
+                );
+                example.input
+            }
+        };
+        let result = (puzzle.measure)(&input, iterations).map_err(|error| error.to_string());
+        if let Err(error) = &result {
+            eprintln!("day {}: {}", puzzle.day, error);
+        }
+        results.insert(puzzle.day, result);
+    }
+
+    println!("{}", format_table(&results, baseline.as_ref()));
+    if let Some(file) = save {
+        let timings: Baseline = results
+            .iter()
+            .filter_map(|(day, result)| Some((*day, *result.as_ref().ok()?)))
+            .collect();
+        std::fs::write(&file, format_baseline(&timings))
+            .map_err(|error| format!("{}: {}", file.display(), error))?;
+    }
+    let failed = results.values().any(Result::is_err);
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use aoc::{Answer, Part};
+
+use crate::answers::Answers;
+use crate::{catch_panic, Puzzle};
+
+/// Result of one part of one day on the personal input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// No accepted answer is recorded yet, `actual` is what the solution gives.
+    Missing {
+        actual: String,
+    },
+    /// The solution does not solve this part yet, and no answer is recorded for it.
+    Unsolved,
+    Error(String),
+    /// There is no personal input for the day.
+    NoInput,
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL: expected {}, got {}", expected, actual)
+            }
+            Status::Missing { actual } => write!(f, "missing: got {}", actual),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Error(error) => write!(f, "ERROR: {}", error),
+            Status::NoInput => write!(f, "no input"),
+        }
+    }
+}
+
+/// Run both parts of the puzzle on its input, if any, and compare them with the recorded answers.
+pub fn verify(puzzle: &Puzzle, input: Option<&str>, answers: &Answers) -> Vec<(Part, Status)> {
+    [Part::One, Part::Two]
+        .into_iter()
+        .map(|part| {
+            let Some(input) = input else {
+                return (part, Status::NoInput);
+            };
+            // a panicking day is reported like an error, so that the other days still run
+            let solved = catch_panic(|| (puzzle.solve_part)(input, part));
+            let status = match (solved, answers.get(puzzle.day, part)) {
+                (Ok(Answer::Unsolved), Some(expected)) => Status::Fail {
+                    expected: expected.to_string(),
+                    actual: "unsolved".to_string(),
+                },
+                (Ok(Answer::Unsolved), None) => Status::Unsolved,
+                (Ok(answer), Some(expected)) if answer.to_string() == expected => Status::Pass,
+                (Ok(answer), Some(expected)) => Status::Fail {
+                    expected: expected.to_string(),
+                    actual: answer.to_string(),
+                },
+                (Ok(answer), None) => Status::Missing {
+                    actual: answer.to_string(),
+                },
+                (Err(error), _) => Status::Error(error.to_string()),
+            };
+            (part, status)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::parse_answers;
+
+    fn puzzle() -> Puzzle {
+        Puzzle {
+            day: 1,
+            solve_part: |input, part| match (input, part) {
+                ("", _) => Err("empty input".into()),
+                ("panic", _) => panic!("index out of bounds"),
+                (_, Part::One) => Ok(Answer::from(input.len())),
+                (_, Part::Two) => Ok(Answer::Unsolved),
+            },
+            measure: |_, _| Err("not measured".into()),
+        }
+    }
+
+    #[test]
+    fn given_recorded_answers_when_verify_then_pass_or_fail() {
+        let answers = parse_answers("[day1]\npart1 = 3").unwrap();
+        assert_eq!(
+            verify(&puzzle(), Some("abc"), &answers),
+            vec![(Part::One, Status::Pass), (Part::Two, Status::Unsolved)]
+        );
+
+        let answers = parse_answers("[day1]\npart1 = 4").unwrap();
+        let statuses = verify(&puzzle(), Some("abc"), &answers);
+        assert_eq!(
+            statuses[0],
+            (
+                Part::One,
+                Status::Fail {
+                    expected: "4".to_string(),
+                    actual: "3".to_string()
+                }
+            )
+        );
+        assert!(statuses[0].1.is_failure());
+    }
+
+    #[test]
+    fn given_no_recorded_answer_when_verify_then_missing_with_actual_answer() {
+        let statuses = verify(&puzzle(), Some("abcd"), &Answers::default());
+
+        assert_eq!(
+            statuses[0],
+            (
+                Part::One,
+                Status::Missing {
+                    actual: "4".to_string()
+                }
+            )
+        );
+        assert!(!statuses[0].1.is_failure());
+    }
+
+    #[test]
+    fn given_no_input_or_error_when_verify_then_reported() {
+        let answers = Answers::default();
+
+        assert_eq!(
+            verify(&puzzle(), None, &answers)[0],
+            (Part::One, Status::NoInput)
+        );
+        assert_eq!(
+            verify(&puzzle(), Some(""), &answers)[0],
+            (Part::One, Status::Error("empty input".to_string()))
+        );
+    }
+
+    #[test]
+    fn given_recorded_answer_for_unsolved_part_when_verify_then_fail() {
+        let answers = parse_answers("[day1]\npart2 = 42").unwrap();
+        let statuses = verify(&puzzle(), Some("abc"), &answers);
+
+        assert_eq!(
+            statuses[1],
+            (
+                Part::Two,
+                Status::Fail {
+                    expected: "42".to_string(),
+                    actual: "unsolved".to_string()
+                }
+            )
+        );
+        assert!(statuses[1].1.is_failure());
+    }
+
+    #[test]
+    fn given_panicking_solution_when_verify_then_error() {
+        let statuses = verify(&puzzle(), Some("panic"), &Answers::default());
+
+        assert_eq!(
+            statuses[0],
+            (
+                Part::One,
+                Status::Error("panicked: index out of bounds".to_string())
+            )
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use runner::examples::{check_examples, examples_dir, load_examples};
+use runner::puzzles;
+
+/// Every day must have examples, and every answer they give must match.
+#[test]
+fn every_day_solves_its_examples() {
+    let mut failures: Vec<String> = vec![];
+    for puzzle in puzzles() {
+        match load_examples(&examples_dir(puzzle.day)) {
+            Ok(examples) if examples.is_empty() => {
+                failures.push(format!("day {}: no example", puzzle.day))
+            }
+            Ok(examples) => failures.extend(
+                check_examples(&puzzle, &examples)
+                    .iter()
+                    .map(|failure| failure.to_string()),
+            ),
+            Err(error) => failures.push(format!("day {}: {}", puzzle.day, error)),
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}